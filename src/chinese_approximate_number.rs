@@ -0,0 +1,30 @@
+/// 約數，例如「十幾」、「三四」或「上百」，以可能的最小值和最大值來表示。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseApproximateNumber {
+    /// 可能的最小值。
+    pub min:         u128,
+    /// 可能的最大值。
+    pub max:         u128,
+    /// 是否為約數。確切的數字的 `min` 和 `max` 會相同，且此值為 `false`。
+    pub approximate: bool,
+}
+
+impl ChineseApproximateNumber {
+    /// 建立確切的數字。
+    #[inline]
+    pub const fn exact(n: u128) -> Self {
+        Self {
+            min: n, max: n, approximate: false
+        }
+    }
+
+    /// 建立約數。
+    #[inline]
+    pub const fn approximate(min: u128, max: u128) -> Self {
+        Self {
+            min,
+            max,
+            approximate: true,
+        }
+    }
+}
@@ -1,6 +1,5 @@
 use enum_ordinalize::Ordinalize;
 
-#[cfg(feature = "number-to-chinese")]
 use crate::{ChineseCase, ChineseVariant};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ordinalize)]
@@ -85,6 +84,22 @@ impl ChineseNumber {
         }
     }
 
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_measure_word_str(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> &'static str {
+        match (self, chinese_case) {
+            (Self::二, ChineseCase::Lower) => match chinese_variant {
+                ChineseVariant::Traditional => "兩",
+                ChineseVariant::Simple => "两",
+            },
+            _ => self.to_str(chinese_variant, chinese_case),
+        }
+    }
+
     #[cfg(feature = "chinese-to-number")]
     #[inline]
     pub(crate) const fn from_char(character: char) -> Option<Self> {
@@ -105,13 +120,17 @@ impl ChineseNumber {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Ordinalize)]
+/// 中文數字的單位，由小到大排列。「億」以上的單位所代表的數值依照算術類型而不同。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Ordinalize)]
 #[ordinalize(impl_trait = false)]
-#[ordinalize(ordinal(pub(crate) fn ordinal))]
+#[ordinalize(ordinal(
+    pub fn ordinal,
+    doc = "取得單位由小到大排列的序號，「分」為 `0`，「個」為 `2`。"
+))]
 #[ordinalize(from_ordinal_unsafe(pub(crate) fn from_ordinal_unsafe))]
 #[allow(dead_code)]
 #[repr(u8)]
-pub(crate) enum ChineseExponent {
+pub enum ChineseExponent {
     分,
     角,
     個,
@@ -130,12 +149,30 @@ pub(crate) enum ChineseExponent {
     正,
     載,
     極,
+    恆河沙,
+    阿僧祇,
+    那由他,
+    不可思議,
+    無量大數,
 }
 
 impl ChineseExponent {
+    /// 取得單位的寫法。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCase, ChineseExponent, ChineseVariant};
+    ///
+    /// assert_eq!("拾", ChineseExponent::十.to_str(ChineseVariant::Traditional, ChineseCase::Upper));
+    /// assert_eq!(
+    ///     "恒河沙",
+    ///     ChineseExponent::恆河沙.to_str(ChineseVariant::Simple, ChineseCase::Lower)
+    /// );
+    /// ```
     #[cfg(feature = "number-to-chinese")]
     #[inline]
-    pub(crate) const fn to_str(
+    pub const fn to_str(
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
@@ -189,6 +226,20 @@ impl ChineseExponent {
                 ChineseVariant::Traditional => "極",
                 ChineseVariant::Simple => "极",
             },
+            Self::恆河沙 => match chinese_variant {
+                ChineseVariant::Traditional => "恆河沙",
+                ChineseVariant::Simple => "恒河沙",
+            },
+            Self::阿僧祇 => "阿僧祇",
+            Self::那由他 => "那由他",
+            Self::不可思議 => match chinese_variant {
+                ChineseVariant::Traditional => "不可思議",
+                ChineseVariant::Simple => "不可思议",
+            },
+            Self::無量大數 => match chinese_variant {
+                ChineseVariant::Traditional => "無量大數",
+                ChineseVariant::Simple => "无量大数",
+            },
         }
     }
 
@@ -217,20 +268,114 @@ impl ChineseExponent {
             _ => None,
         }
     }
+
+    #[cfg(feature = "chinese-to-number")]
+    pub(crate) fn from_chars_rev(mut chars: impl Iterator<Item = char>) -> Option<(Self, usize)> {
+        let last = chars.next()?;
+
+        // The glyphs are listed from the second last character to the first one.
+        let (exp, glyphs): (Self, &[&[char]]) = match last {
+            '沙' => (Self::恆河沙, &[&['河'], &['恆', '恒']]),
+            '祇' => (Self::阿僧祇, &[&['僧'], &['阿']]),
+            '他' => (Self::那由他, &[&['由'], &['那']]),
+            '議' | '议' => (Self::不可思議, &[&['思'], &['可'], &['不']]),
+            '數' | '数' => (Self::無量大數, &[&['大'], &['量'], &['無', '无']]),
+            _ => return Self::from_char(last).map(|exp| (exp, 1)),
+        };
+
+        for glyph in glyphs {
+            match chars.next() {
+                Some(c) if glyph.contains(&c) => (),
+                _ => return None,
+            }
+        }
+
+        Some((exp, glyphs.len() + 1))
+    }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Ordinalize)]
+#[ordinalize(impl_trait = false)]
+#[ordinalize(ordinal(pub(crate) fn ordinal))]
+#[ordinalize(from_ordinal_unsafe(pub(crate) fn from_ordinal_unsafe))]
+#[ordinalize(variants(pub(crate) fn variants))]
 #[allow(dead_code)]
 #[repr(u8)]
-pub(crate) enum ChineseSign {
+pub(crate) enum ChineseDecimalUnit {
+    分,
+    厘,
+    毫,
+    絲,
+    忽,
+    微,
+    纖,
+    沙,
+}
+
+impl ChineseDecimalUnit {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match self {
+            Self::分 => "分",
+            Self::厘 => match chinese_variant {
+                ChineseVariant::Traditional => "釐",
+                ChineseVariant::Simple => "厘",
+            },
+            Self::毫 => "毫",
+            Self::絲 => match chinese_variant {
+                ChineseVariant::Traditional => "絲",
+                ChineseVariant::Simple => "丝",
+            },
+            Self::忽 => "忽",
+            Self::微 => "微",
+            Self::纖 => match chinese_variant {
+                ChineseVariant::Traditional => "纖",
+                ChineseVariant::Simple => "纤",
+            },
+            Self::沙 => "沙",
+        }
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '分' => Some(Self::分),
+            '厘' | '釐' => Some(Self::厘),
+            '毫' => Some(Self::毫),
+            '絲' | '丝' => Some(Self::絲),
+            '忽' => Some(Self::忽),
+            '微' => Some(Self::微),
+            '纖' | '纤' => Some(Self::纖),
+            '沙' => Some(Self::沙),
+            _ => None,
+        }
+    }
+}
+
+/// 中文數字的正負號。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[allow(dead_code)]
+#[repr(u8)]
+pub enum ChineseSign {
     正,
     負,
 }
 
 impl ChineseSign {
+    /// 取得正負號的寫法。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseSign, ChineseVariant};
+    ///
+    /// assert_eq!("负", ChineseSign::負.to_str(ChineseVariant::Simple));
+    /// ```
     #[cfg(feature = "number-to-chinese")]
     #[inline]
-    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+    pub const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
         match self {
             Self::正 => "正",
             Self::負 => match chinese_variant {
@@ -273,3 +418,85 @@ impl ChinesePoint {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[allow(dead_code)]
+pub(crate) enum ChineseApproximation {
+    幾,
+    上,
+    多,
+    餘,
+}
+
+impl ChineseApproximation {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match self {
+            Self::幾 => match chinese_variant {
+                ChineseVariant::Traditional => "幾",
+                ChineseVariant::Simple => "几",
+            },
+            Self::上 => "上",
+            Self::多 => "多",
+            Self::餘 => match chinese_variant {
+                ChineseVariant::Traditional => "餘",
+                ChineseVariant::Simple => "余",
+            },
+        }
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '幾' | '几' => Some(Self::幾),
+            '上' => Some(Self::上),
+            '多' => Some(Self::多),
+            '餘' | '余' => Some(Self::餘),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chinese-to-number")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ChineseGlyphStyle {
+    pub(crate) variant: Option<ChineseVariant>,
+    pub(crate) case:    Option<ChineseCase>,
+}
+
+#[cfg(feature = "chinese-to-number")]
+impl ChineseGlyphStyle {
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        use ChineseCase::{Lower, Upper};
+        use ChineseVariant::{Simple, Traditional};
+
+        let (variant, case) = match character {
+            '零' | '〇' | '0'..='9' => (None, None),
+            '一' | '二' | '三' | '四' | '五' | '六' | '七' | '八' | '九' | '十' | '百' | '千' => {
+                (None, Some(Lower))
+            },
+            '壹' | '肆' | '伍' | '柒' | '捌' | '玖' | '拾' | '佰' | '仟' | '叄' => {
+                (None, Some(Upper))
+            },
+            '貳' | '貮' | '參' | '陸' => (Some(Traditional), Some(Upper)),
+            '贰' | '叁' | '参' | '陆' => (Some(Simple), Some(Upper)),
+            '兩' => (Some(Traditional), Some(Lower)),
+            '两' => (Some(Simple), Some(Lower)),
+            '個' | '萬' | '億' | '溝' | '澗' | '載' | '極' | '恆' | '議' | '無' | '數' | '釐'
+            | '絲' | '纖' | '負' | '點' => (Some(Traditional), None),
+            '个' | '万' | '亿' | '沟' | '涧' | '载' | '极' | '恒' | '议' | '无' | '数' | '厘'
+            | '丝' | '纤' | '负' | '点' => (Some(Simple), None),
+            '分' | '角' | '兆' | '京' | '垓' | '秭' | '穰' | '正' | '毫' | '忽' | '微' | '沙' => {
+                (None, None)
+            },
+            _ => return None,
+        };
+
+        Some(Self {
+            variant,
+            case,
+        })
+    }
+}
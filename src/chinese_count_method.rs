@@ -1,9 +1,10 @@
 use enum_ordinalize::Ordinalize;
 
-/// 根據 **五經算術** 將大的單位分為 **上數** (`High`)、**中數** (`Middle`)、**下數** (`Low`) 三種類型，再加上現代使用的 **萬進** (`TenThousand`)。
+/// 根據 **五經算術** 將大的單位分為 **上數** (`High`)、**中數** (`Middle`)、**下數** (`Low`) 三種類型，再加上現代使用的 **萬進** (`TenThousand`) 和 **大陸用法** (`Mainland`)。
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ordinalize)]
 #[ordinalize(impl_trait = false)]
 #[ordinalize(variants(pub fn variants, doc = "算術類型的所有變體的陣列。"))]
+#[non_exhaustive]
 pub enum ChineseCountMethod {
     /// 下數者，十十變之。若言十萬曰億，十億曰兆，十兆曰京也。
     Low,
@@ -13,4 +14,6 @@ pub enum ChineseCountMethod {
     Middle,
     /// 上數者，數窮則變。若言萬萬曰億，億億曰兆、兆兆曰京也。
     High,
+    /// 大陸用法者，萬萬曰億，萬億、億億以億累之。兆從國際單位制，百萬曰兆；京以上同萬進。
+    Mainland,
 }
@@ -0,0 +1,50 @@
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+use crate::ChineseExponent;
+
+/// **萬進** 中直接使用的最大單位。更大的數值以此單位組合而成，例如最大單位為「億」時，一萬億為 `10^12`，一億億為 `10^16`。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseLargestUnit {
+    萬,
+    億,
+    兆,
+    京,
+    垓,
+    秭,
+    穰,
+    溝,
+    澗,
+    正,
+    載,
+    極,
+    恆河沙,
+    阿僧祇,
+    那由他,
+    不可思議,
+    無量大數,
+}
+
+impl ChineseLargestUnit {
+    #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+    #[inline]
+    pub(crate) const fn to_exponent(self) -> ChineseExponent {
+        match self {
+            Self::萬 => ChineseExponent::萬,
+            Self::億 => ChineseExponent::億,
+            Self::兆 => ChineseExponent::兆,
+            Self::京 => ChineseExponent::京,
+            Self::垓 => ChineseExponent::垓,
+            Self::秭 => ChineseExponent::秭,
+            Self::穰 => ChineseExponent::穰,
+            Self::溝 => ChineseExponent::溝,
+            Self::澗 => ChineseExponent::澗,
+            Self::正 => ChineseExponent::正,
+            Self::載 => ChineseExponent::載,
+            Self::極 => ChineseExponent::極,
+            Self::恆河沙 => ChineseExponent::恆河沙,
+            Self::阿僧祇 => ChineseExponent::阿僧祇,
+            Self::那由他 => ChineseExponent::那由他,
+            Self::不可思議 => ChineseExponent::不可思議,
+            Self::無量大數 => ChineseExponent::無量大數,
+        }
+    }
+}
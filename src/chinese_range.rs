@@ -0,0 +1,171 @@
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "chinese-to-number")]
+use num_traits::NumCast;
+
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+use crate::{ChineseCountMethod, ChineseExponent};
+
+/// 數值範圍，例如「三至五萬」或「十到二十」。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseRange<T> {
+    /// 範圍的下限。
+    pub low:  T,
+    /// 範圍的上限。
+    pub high: T,
+}
+
+impl<T> ChineseRange<T> {
+    /// 建立數值範圍。
+    #[inline]
+    pub const fn new(low: T, high: T) -> Self {
+        Self {
+            low,
+            high,
+        }
+    }
+}
+
+/// 數值範圍的分隔字。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseRangeSeparator {
+    /// 「至」，例如「三至五萬」。
+    To,
+    /// 「到」，例如「十到二十」。
+    Until,
+}
+
+impl ChineseRangeSeparator {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str(self) -> &'static str {
+        match self {
+            Self::To => "至",
+            Self::Until => "到",
+        }
+    }
+}
+
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+fn range_unit_values(method: ChineseCountMethod) -> Vec<u128> {
+    let largest = match method {
+        ChineseCountMethod::Mainland => ChineseExponent::億,
+        _ => ChineseExponent::無量大數,
+    };
+
+    let exponents = (ChineseExponent::十.ordinal()..=largest.ordinal())
+        .map(|ordinal| unsafe { ChineseExponent::from_ordinal_unsafe(ordinal) });
+
+    exponents
+        .map_while(|exp| {
+            let distance = |from: ChineseExponent| (exp.ordinal() - from.ordinal()) as u32;
+
+            let power = match method {
+                _ if exp <= ChineseExponent::萬 => distance(ChineseExponent::個),
+                ChineseCountMethod::Low => distance(ChineseExponent::個),
+                ChineseCountMethod::Middle => 8 * distance(ChineseExponent::萬),
+                ChineseCountMethod::High => 4 << distance(ChineseExponent::萬),
+                _ => 4 * distance(ChineseExponent::千),
+            };
+
+            10u128.checked_pow(power)
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+#[inline]
+fn range_unit_limit(values: &[u128], index: usize) -> Option<u128> {
+    values.get(index + 1).map(|next| next / values[index])
+}
+
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+fn range_trailing_units(values: &[u128], mut value: u128) -> Vec<usize> {
+    let mut units = Vec::new();
+
+    while value > 0
+        && let Some(index) = values.iter().rposition(|&unit| value.is_multiple_of(unit))
+    {
+        units.push(index);
+
+        value /= values[index];
+
+        if let Some(limit) = range_unit_limit(values, index) {
+            value %= limit;
+        }
+    }
+
+    units
+}
+
+#[cfg(feature = "number-to-chinese")]
+pub(crate) fn shared_range_low(method: ChineseCountMethod, low: u128, high: u128) -> Option<u128> {
+    let values = range_unit_values(method);
+
+    // The shared unit must be at least 百, and the rest of the low end cannot contain a unit as large as it, so "一億零三萬至一億零五萬" is kept.
+    match range_trailing_units(&values, high).first() {
+        Some(&index) if index > 0 && low < high && low.is_multiple_of(values[index]) => {
+            let low = low / values[index];
+
+            let fits = range_unit_limit(&values, index).is_none_or(|limit| low < limit);
+
+            (low > 0 && fits).then_some(low)
+        },
+        _ => None,
+    }
+}
+
+#[cfg(feature = "chinese-to-number")]
+pub(crate) fn split_integral<T: NumCast + PartialEq + Copy>(value: T) -> Option<(bool, u128)> {
+    if let Some(magnitude) = value.to_u128() {
+        (T::from(magnitude) == Some(value)).then_some((false, magnitude))
+    } else {
+        let value_i128 = value.to_i128()?;
+
+        (T::from(value_i128) == Some(value)).then_some((true, value_i128.unsigned_abs()))
+    }
+}
+
+#[cfg(feature = "chinese-to-number")]
+pub(crate) fn read_range_low<T: NumCast + PartialOrd + Copy>(
+    method: ChineseCountMethod,
+    low: T,
+    high: T,
+) -> T {
+    let values = range_unit_values(method);
+
+    let (Some((negative, magnitude)), Some((_, high_magnitude))) =
+        (split_integral(low), split_integral(high))
+    else {
+        return low;
+    };
+
+    let units = range_trailing_units(&values, high_magnitude);
+
+    if magnitude == 0 || units.first().is_none_or(|&index| index == 0) {
+        return low;
+    }
+
+    for len in (1..=units.len()).rev() {
+        let units = &units[..len];
+
+        if range_unit_limit(&values, units[len - 1]).is_some_and(|limit| magnitude >= limit) {
+            continue;
+        }
+
+        let unit = units.iter().try_fold(1u128, |unit, &index| unit.checked_mul(values[index]));
+
+        let read = unit.and_then(|unit| magnitude.checked_mul(unit)).and_then(|read| {
+            if negative { T::from(-i128::try_from(read).ok()?) } else { T::from(read) }
+        });
+
+        if let Some(read) = read
+            && read < high
+        {
+            return read;
+        }
+    }
+
+    low
+}
@@ -0,0 +1,230 @@
+use alloc::string::String;
+
+use super::{
+    char_after, char_before, chinese_to_unsigned_integer, exponent_at, scanner::unit_at, unexpected,
+};
+use crate::{
+    ChineseApproximateNumber, ChineseCountMethod, ChineseNumberExpected, ChineseNumberPosition,
+    ChineseToNumberError,
+    chinese_characters::{ChineseApproximation, ChineseExponent, ChineseNumber},
+};
+
+#[inline]
+fn chinese_digit(c: char) -> Option<u8> {
+    if c.is_ascii_digit() {
+        return None;
+    }
+
+    match ChineseNumber::from_char(c) {
+        Some(n) if n != ChineseNumber::零 && n != ChineseNumber::十 => Some(n.ordinal()),
+        _ => None,
+    }
+}
+
+#[inline]
+fn digit_to_char(d: u8) -> char {
+    debug_assert!((1..=9).contains(&d));
+
+    char::from(b'0' + d)
+}
+
+#[inline]
+fn byte_index_of(s: &str, index: usize) -> usize {
+    s.char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .nth(index)
+        .map_or(s.len(), |(byte_index, _)| byte_index)
+}
+
+fn chinese_to_approximate_number(
+    method: ChineseCountMethod,
+    s: &str,
+) -> Result<ChineseApproximateNumber, ChineseToNumberError> {
+    let Some((first_index, first)) = char_after(s, 0) else {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    };
+
+    // "上百" is read as at least "一百".
+    let above = ChineseApproximation::from_char(first) == Some(ChineseApproximation::上)
+        && char_after(s, first_index + first.len_utf8()).is_some_and(|(_, c)| {
+            matches!(ChineseExponent::from_char(c), Some(exp) if exp >= ChineseExponent::十)
+        });
+
+    // "一百多" and "三十多萬" are read as more than "一百" and "三十萬". Only units can follow 多.
+    let more = s.char_indices().find(|&(index, c)| {
+        index > first_index
+            && matches!(
+                ChineseApproximation::from_char(c),
+                Some(ChineseApproximation::多 | ChineseApproximation::餘)
+            )
+    });
+
+    let (end, following_units) = match more {
+        Some((index, c)) => {
+            let units_start = index + c.len_utf8();
+            let mut position = units_start;
+
+            while let Some((index, _)) = char_after(s, position) {
+                match unit_at(s, index) {
+                    Some((_, end)) => position = end,
+                    None => return Err(unexpected(s, index, ChineseNumberExpected::Unit)),
+                }
+            }
+
+            (index, &s[units_start..])
+        },
+        None => (s.len(), ""),
+    };
+
+    // The digits replaced by 幾 are written as Arabic digits, which the parser accepts as well.
+    let mut min_chars = String::with_capacity(end + following_units.len());
+    let mut max_chars = String::with_capacity(end + following_units.len());
+    let mut approximate = above || more.is_some();
+
+    // The indices of the non-whitespace characters which are left out of `min_chars` and `max_chars`, to locate errors in `s`.
+    let mut removed_index: Option<usize> = None;
+    let more_index = more.map(|_| s[..end].chars().filter(|c| !c.is_whitespace()).count());
+
+    let mut chars = s[..end].char_indices().filter(|(_, c)| !c.is_whitespace()).enumerate();
+
+    while let Some((char_index, (index, c))) = chars.next() {
+        if above && index == first_index {
+            min_chars.push('一');
+            max_chars.push('一');
+        } else if ChineseApproximation::from_char(c) == Some(ChineseApproximation::幾) {
+            // "幾千" is at least two thousand, but "十幾" can be eleven.
+            let min = if index == first_index { 2 } else { 1 };
+
+            min_chars.push(digit_to_char(min));
+            max_chars.push(digit_to_char(9));
+
+            approximate = true;
+        } else {
+            let next_index = index + c.len_utf8();
+
+            let pair = match (chinese_digit(c), char_after(&s[..end], next_index)) {
+                (Some(d), Some((next_index, next))) if removed_index.is_none() => {
+                    let before_is_digit =
+                        char_before(s, index).is_some_and(|(_, c)| chinese_digit(c).is_some());
+                    let after_is_digit = char_after(&s[..end], next_index + next.len_utf8())
+                        .is_some_and(|(_, c)| chinese_digit(c).is_some());
+
+                    if chinese_digit(next) == Some(d + 1) && !before_is_digit && !after_is_digit {
+                        Some(next)
+                    } else {
+                        None
+                    }
+                },
+                _ => None,
+            };
+
+            match pair {
+                // "三四十" is read as "三十" to "四十".
+                Some(next) => {
+                    min_chars.push(c);
+                    max_chars.push(next);
+
+                    removed_index = Some(char_index + 1);
+                    approximate = true;
+
+                    chars.next();
+                },
+                None => {
+                    min_chars.push(c);
+                    max_chars.push(c);
+                },
+            }
+        }
+    }
+
+    if more.is_some() {
+        min_chars.push_str(following_units);
+        max_chars.push_str(following_units);
+    }
+
+    // Errors are reported at the positions of the original chars, which have the second digit of a merged pair and 多 in addition.
+    let map_error = |error: ChineseToNumberError| match error.position() {
+        Some(position) => {
+            let mut index = position.char_index;
+
+            for skipped_index in [removed_index, more_index].into_iter().flatten() {
+                if index >= skipped_index {
+                    index += 1;
+                }
+            }
+
+            error.with_position(ChineseNumberPosition::locate(s, byte_index_of(s, index)))
+        },
+        None => error,
+    };
+
+    let min = chinese_to_unsigned_integer(method, &min_chars).map_err(map_error)?;
+    let mut max = chinese_to_unsigned_integer(method, &max_chars).map_err(map_error)?;
+
+    if above {
+        max = max.checked_mul(10).ok_or(ChineseToNumberError::Overflow)? - 1;
+    }
+
+    if more.is_some() {
+        // The amount after 多 is less than one of the last unit before it, such as 十 in "三十多" and 十萬 in "三十多萬".
+        let step = match char_before(s, end).and_then(|(index, _)| exponent_at(s, index)) {
+            Some((exp, start)) if exp >= ChineseExponent::十 => {
+                let mut unit_chars = String::from('一');
+
+                unit_chars.push_str(&s[start..end]);
+                unit_chars.push_str(following_units);
+
+                chinese_to_unsigned_integer(method, &unit_chars)?
+            },
+            _ => {
+                return Err(ChineseToNumberError::incorrect(s, end, ChineseNumberExpected::Unit));
+            },
+        };
+
+        return Ok(ChineseApproximateNumber::approximate(
+            min.checked_add(1).ok_or(ChineseToNumberError::Overflow)?,
+            max.checked_add(step - 1).ok_or(ChineseToNumberError::Overflow)?,
+        ));
+    }
+
+    if approximate {
+        Ok(ChineseApproximateNumber::approximate(min, max))
+    } else {
+        Ok(ChineseApproximateNumber::exact(min))
+    }
+}
+
+/// 將中文約數轉成可能的最小值和最大值，例如「十幾」、「三四」、「十七八」、「上百」、「幾千」、「一百多」和「三十多萬」。確切的中文數字也可以轉換。
+///
+/// * 「幾」在開頭時代表二到九，在其它位置時代表一到九。
+/// * 相鄰且連續的兩個數字代表其中之一，例如「三四」代表三或四。
+/// * 「上」加上單位代表至少一個該單位，且少於十個該單位，例如「上百」代表一百到九百九十九。
+/// * 數字後的「多」或「餘」代表多出少於一個最後的單位，例如「三十多」代表三十一到三十九。後面還可以再接更大的單位，例如「三十多萬」代表三十萬零一到三十九萬九千九百九十九。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_chinese_to_approximate_number, ChineseApproximateNumber, ChineseCountMethod,
+/// };
+///
+/// assert_eq!(
+///     ChineseApproximateNumber::approximate(11, 19),
+///     from_chinese_to_approximate_number("十幾", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     ChineseApproximateNumber::approximate(100, 999),
+///     from_chinese_to_approximate_number("上百", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     ChineseApproximateNumber::exact(120),
+///     from_chinese_to_approximate_number("一百二十", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_chinese_to_approximate_number<S: AsRef<str>>(
+    chinese_number: S,
+    method: ChineseCountMethod,
+) -> Result<ChineseApproximateNumber, ChineseToNumberError> {
+    chinese_to_approximate_number(method, chinese_number.as_ref())
+}
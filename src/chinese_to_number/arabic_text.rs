@@ -0,0 +1,258 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{
+    chinese_to_signed_integer,
+    scanner::{is_single_upper_case_digit, run_boundaries, unit_at},
+};
+use crate::{
+    ChineseCountMethod, ChineseToNumberError,
+    chinese_characters::{ChineseApproximation, ChineseNumber, ChinesePoint},
+};
+
+/// 預設不會被轉換的詞語，例如成語和含有數字的常用詞。
+pub const DEFAULT_CHINESE_NUMBER_EXCLUSIONS: &[&str] = &[
+    "一心一意", "一模一樣", "一模一样", "一五一十", "三心二意", "三五成群", "七上八下", "亂七八糟",
+    "乱七八糟", "千方百計", "千方百计", "一些", "一起", "一直", "一定", "一樣", "一样", "一般",
+    "一切", "一旦", "一致", "唯一", "統一", "统一", "萬一", "万一", "十分", "零下",
+];
+
+/// 將文字中的中文數字換成阿拉伯數字時的設定。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_text_to_arabic, ChineseToArabicOptions};
+///
+/// let options = ChineseToArabicOptions {
+///     exclusions: &["一樣"],
+///     ..ChineseToArabicOptions::default()
+/// };
+///
+/// assert_eq!("一樣有3個", from_chinese_text_to_arabic("一樣有三個", options));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseToArabicOptions<'a> {
+    /// 有單位的中文數字所使用的算術類型。
+    pub method:     ChineseCountMethod,
+    /// 不會被轉換的詞語。
+    pub exclusions: &'a [&'a str],
+}
+
+impl Default for ChineseToArabicOptions<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            method:     ChineseCountMethod::TenThousand,
+            exclusions: DEFAULT_CHINESE_NUMBER_EXCLUSIONS,
+        }
+    }
+}
+
+/// 將文字中的中文數字換成阿拉伯數字。
+///
+/// 沒有單位且有三位以上或含有零的數字，例如年份和電話號碼，會逐字轉換並保留開頭的零；有單位的數字，例如金額，則會依照 `options.method` 計算數值。「點」後面的數字會被當作小數，例如「三點五公斤」會轉換成「3.5公斤」。「兩三個」、「七八十」、「十幾個」之類的約數，`options.exclusions` 中的詞語，以及無法轉換的部份，會保持原樣。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_text_to_arabic, ChineseToArabicOptions};
+///
+/// assert_eq!(
+///     "2026年10月18日下午3點，花了1200元",
+///     from_chinese_text_to_arabic(
+///         "二〇二六年十月十八日下午三點，花了一千二百元",
+///         ChineseToArabicOptions::default()
+///     )
+/// );
+/// assert_eq!(
+///     "他一心一意地撥了0912345678",
+///     from_chinese_text_to_arabic("他一心一意地撥了〇九一二三四五六七八", ChineseToArabicOptions::default())
+/// );
+/// ```
+pub fn from_chinese_text_to_arabic<S: AsRef<str>>(
+    text: S,
+    options: ChineseToArabicOptions,
+) -> String {
+    let text = text.as_ref();
+
+    let mut result = String::with_capacity(text.len());
+    let mut position = 0;
+
+    while let Some(c) = text[position..].chars().next() {
+        if let Some(length) = exclusion_length(text, position, options.exclusions) {
+            result.push_str(&text[position..position + length]);
+
+            position += length;
+
+            continue;
+        }
+
+        match rewrite_run(text, position, options) {
+            Some((arabic, end)) => {
+                result.push_str(&arabic);
+
+                position = end;
+            },
+            None => {
+                result.push(c);
+
+                position += c.len_utf8();
+            },
+        }
+    }
+
+    result
+}
+
+fn rewrite_run(
+    text: &str,
+    start: usize,
+    options: ChineseToArabicOptions,
+) -> Option<(String, usize)> {
+    let mut boundaries = run_boundaries(text, start, false);
+
+    // Cut the run before an excluded word, such as 一心一意 in 十一心一意.
+    if let Some(&end) = boundaries.last()
+        && let Some((index, _)) = text[start..end]
+            .char_indices()
+            .skip(1)
+            .find(|&(index, _)| exclusion_length(text, start + index, options.exclusions).is_some())
+    {
+        boundaries.retain(|&boundary| boundary <= start + index);
+    }
+
+    let &end = boundaries.last()?;
+
+    let run = &text[start..end];
+
+    let (negative, digits) = match run.strip_prefix(['負', '负']) {
+        Some(digits) => (true, digits),
+        None => (false, run),
+    };
+
+    // Approximations, such as 十幾個, are kept as they are.
+    if text[end..].chars().next().is_some_and(is_approximation_suffix) {
+        return Some((run.to_string(), end));
+    }
+
+    match digits_to_arabic(digits) {
+        // Digits without units, such as years and phone numbers, are converted one by one.
+        Some(arabic_digits) if arabic_digits.len() >= 3 || digits.contains(['〇', '零']) => {
+            let mut arabic = String::with_capacity(arabic_digits.len() + 1);
+
+            if negative {
+                arabic.push('-');
+            }
+
+            arabic.push_str(&arabic_digits);
+
+            Some(push_fraction(text, negative, arabic, end))
+        },
+        // Two digits, such as 五三, are neither a sequence of digits nor a number with units.
+        Some(arabic_digits) if arabic_digits.len() > 1 => Some((run.to_string(), end)),
+        // Approximations, such as 七八十, are kept as they are.
+        _ if has_consecutive_digits(digits) => Some((run.to_string(), end)),
+        _ => {
+            for &boundary in boundaries.iter().rev() {
+                let candidate = &text[start..boundary];
+
+                if is_single_upper_case_digit(candidate) {
+                    continue;
+                }
+
+                match chinese_to_signed_integer(options.method, candidate) {
+                    Ok(value) => {
+                        return Some(push_fraction(text, negative, value.to_string(), boundary));
+                    },
+                    // A shorter candidate would be a different number.
+                    Err(ChineseToNumberError::Overflow | ChineseToNumberError::Underflow) => {
+                        return Some((run.to_string(), end));
+                    },
+                    Err(_) => (),
+                }
+            }
+
+            None
+        },
+    }
+}
+
+fn push_fraction(
+    text: &str,
+    negative: bool,
+    mut arabic: String,
+    end: usize,
+) -> (String, usize) {
+    match fraction_at(text, end) {
+        Some((fraction, fraction_end)) => {
+            if negative && !arabic.starts_with('-') {
+                arabic.insert(0, '-');
+            }
+
+            arabic.push('.');
+            arabic.push_str(&fraction);
+
+            (arabic, fraction_end)
+        },
+        None => (arabic, end),
+    }
+}
+
+fn fraction_at(text: &str, index: usize) -> Option<(String, usize)> {
+    let c = text[index..].chars().next()?;
+
+    ChinesePoint::from_char(c)?;
+
+    let start = index + c.len_utf8();
+
+    let end = text[start..]
+        .char_indices()
+        .find(|&(_, c)| c.is_ascii() || ChineseNumber::from_char(c).is_none())
+        .map_or(text.len(), |(i, _)| start + i);
+
+    if end == start || unit_at(text, end).is_some() {
+        return None;
+    }
+
+    digits_to_arabic(&text[start..end]).map(|fraction| (fraction, end))
+}
+
+fn digits_to_arabic(s: &str) -> Option<String> {
+    s.chars()
+        .map(|c| match ChineseNumber::from_char(c) {
+            Some(ChineseNumber::十) | None => None,
+            Some(cn) => Some(char::from(b'0' + cn.ordinal())),
+        })
+        .collect()
+}
+
+fn has_consecutive_digits(s: &str) -> bool {
+    let digits: Vec<Option<ChineseNumber>> = s.chars().map(ChineseNumber::from_char).collect();
+
+    digits.windows(2).any(|pair| match pair {
+        [Some(a), Some(b)] if *a != ChineseNumber::十 && *b != ChineseNumber::十 => {
+            a.ordinal() + 1 == b.ordinal()
+        },
+        _ => false,
+    })
+}
+
+#[inline]
+fn is_approximation_suffix(c: char) -> bool {
+    matches!(
+        ChineseApproximation::from_char(c),
+        Some(ChineseApproximation::幾 | ChineseApproximation::多 | ChineseApproximation::餘)
+    ) || matches!(c, '來' | '来')
+}
+
+#[inline]
+fn exclusion_length(text: &str, index: usize, exclusions: &[&str]) -> Option<usize> {
+    exclusions
+        .iter()
+        .filter(|exclusion| !exclusion.is_empty() && text[index..].starts_with(*exclusion))
+        .map(|exclusion| exclusion.len())
+        .max()
+}
@@ -0,0 +1,297 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(feature = "number-to-chinese")]
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "number-to-chinese")]
+use num_bigint::BigUint;
+
+use super::{
+    ParsedInteger, UnsignedInteger, chinese_to_unsigned, default_compound_unit, get_exp_base,
+};
+#[cfg(feature = "number-to-chinese")]
+use crate::{ChineseCase, ChineseVariant};
+use crate::{
+    ChineseCountMethod, ChineseExponent, ChineseToNumberError, chinese_characters::ChineseNumber,
+};
+
+/// 中文數字的語法樹，和解析中文數字時的遞迴結構相同，可以用來說明數值是如何得出的。
+///
+/// 使用 `Display` 可以將語法樹寫成算式，例如「三千二百萬」會寫成 `(3×千 + 2×百)×萬`。
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseNumberAst {
+    /// 一到九的數字。
+    Digit(u8),
+    /// 用來佔位的「零」。
+    Zero,
+    /// 係數乘上單位，例如「三千」。省略「一」的「十」，例如「十二」中的「十」，其係數為 `None`。
+    Unit {
+        coefficient: Option<Box<ChineseNumberAst>>,
+        unit:        ChineseExponent,
+    },
+    /// 由大到小相加的部份，例如「三千零二」。
+    Sum(Vec<ChineseNumberAst>),
+    /// 省略單位的數字，例如「一百二」中的「二」，其數值為 `digit` 乘上 `unit` 再除以十。
+    Abbreviated {
+        digit: u8,
+        unit:  ChineseExponent,
+    },
+}
+
+impl ChineseNumberAst {
+    /// 依照算術類型計算語法樹的數值。
+    #[inline]
+    pub fn to_u128(&self, method: ChineseCountMethod) -> Result<u128, ChineseToNumberError> {
+        self.evaluate(method)
+    }
+
+    /// 依照算術類型計算語法樹的數值，沒有上限。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub fn to_big_uint(&self, method: ChineseCountMethod) -> Result<BigUint, ChineseToNumberError> {
+        self.evaluate(method)
+    }
+
+    /// 將語法樹寫回中文數字。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{from_chinese_to_ast, ChineseCase, ChineseCountMethod, ChineseVariant};
+    ///
+    /// let ast = from_chinese_to_ast("壹佰零貳", ChineseCountMethod::TenThousand).unwrap();
+    ///
+    /// assert_eq!("一百零二", ast.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower));
+    /// ```
+    #[cfg(feature = "number-to-chinese")]
+    pub fn to_chinese(&self, chinese_variant: ChineseVariant, chinese_case: ChineseCase) -> String {
+        let mut s = String::new();
+
+        self.push_chinese(&mut s, chinese_variant, chinese_case);
+
+        s
+    }
+
+    #[cfg(feature = "number-to-chinese")]
+    fn push_chinese(
+        &self,
+        s: &mut String,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) {
+        match self {
+            Self::Digit(digit) | Self::Abbreviated {
+                digit, ..
+            } => {
+                let number = unsafe { ChineseNumber::from_ordinal_unsafe(*digit) };
+
+                s.push_str(number.to_str(chinese_variant, chinese_case));
+            },
+            Self::Zero => s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case)),
+            Self::Unit {
+                coefficient,
+                unit,
+            } => {
+                if let Some(coefficient) = coefficient {
+                    coefficient.push_chinese(s, chinese_variant, chinese_case);
+                }
+
+                s.push_str(unit.to_str(chinese_variant, chinese_case));
+            },
+            Self::Sum(terms) => {
+                for term in terms {
+                    term.push_chinese(s, chinese_variant, chinese_case);
+                }
+            },
+        }
+    }
+
+    fn evaluate<T: UnsignedInteger>(
+        &self,
+        method: ChineseCountMethod,
+    ) -> Result<T, ChineseToNumberError> {
+        match self {
+            Self::Digit(digit) => Ok(T::from(*digit as u32)),
+            Self::Zero => Ok(T::zero()),
+            Self::Unit {
+                coefficient,
+                unit,
+            } => {
+                let coefficient = match coefficient {
+                    Some(coefficient) => coefficient.evaluate(method)?,
+                    None => T::one(),
+                };
+
+                coefficient
+                    .checked_mul(&get_exp_base(method, *unit)?)
+                    .ok_or(ChineseToNumberError::Overflow)
+            },
+            Self::Sum(terms) => terms.iter().try_fold(T::zero(), |sum, term| {
+                sum.checked_add(&term.evaluate(method)?).ok_or(ChineseToNumberError::Overflow)
+            }),
+            Self::Abbreviated {
+                digit,
+                unit,
+            } => Ok(T::from(*digit as u32)
+                .checked_mul(&get_exp_base(method, *unit)?)
+                .ok_or(ChineseToNumberError::Overflow)?
+                / T::from(10)),
+        }
+    }
+
+    pub(crate) fn has_abbreviation(&self) -> bool {
+        match self {
+            Self::Abbreviated {
+                ..
+            } => true,
+            Self::Unit {
+                coefficient: Some(coefficient),
+                ..
+            } => coefficient.has_abbreviation(),
+            Self::Sum(terms) => terms.iter().any(Self::has_abbreviation),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn into_terms(self) -> Vec<Self> {
+        match self {
+            Self::Sum(terms) => terms,
+            _ => vec![self],
+        }
+    }
+}
+
+impl ParsedInteger for ChineseNumberAst {
+    type Unit = ChineseExponent;
+
+    #[inline]
+    fn unit(
+        _method: ChineseCountMethod,
+        exp: ChineseExponent,
+    ) -> Result<ChineseExponent, ChineseToNumberError> {
+        Ok(exp)
+    }
+
+    #[inline]
+    fn digit(n: ChineseNumber) -> Self {
+        match n {
+            ChineseNumber::零 => Self::Zero,
+            _ => Self::Digit(n.ordinal()),
+        }
+    }
+
+    #[inline]
+    fn implicit_ten() -> Self {
+        Self::Unit {
+            coefficient: None,
+            unit:        ChineseExponent::十,
+        }
+    }
+
+    #[inline]
+    fn empty() -> Self {
+        Self::Sum(Vec::new())
+    }
+
+    #[inline]
+    fn scale(self, unit: &ChineseExponent) -> Result<Self, ChineseToNumberError> {
+        match unit {
+            ChineseExponent::個 => Ok(self),
+            _ => Ok(Self::Unit {
+                coefficient: Some(Box::new(self)),
+                unit:        *unit,
+            }),
+        }
+    }
+
+    fn add_higher(self, higher: Self) -> Result<Self, ChineseToNumberError> {
+        let mut terms = higher.into_terms();
+
+        terms.extend(self.into_terms());
+
+        if terms.len() == 1 {
+            Ok(terms.remove(0))
+        } else {
+            Ok(Self::Sum(terms))
+        }
+    }
+
+    fn abbreviate(self) -> Self {
+        match self {
+            Self::Unit {
+                coefficient: Some(coefficient),
+                unit,
+            } => match *coefficient {
+                Self::Digit(digit) => Self::Abbreviated {
+                    digit,
+                    unit,
+                },
+                coefficient => Self::Unit {
+                    coefficient: Some(Box::new(coefficient)),
+                    unit,
+                },
+            },
+            _ => self,
+        }
+    }
+}
+
+impl Display for ChineseNumberAst {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Digit(digit) => Display::fmt(digit, f),
+            Self::Zero => f.write_str("0"),
+            Self::Unit {
+                coefficient,
+                unit,
+            } => match coefficient {
+                Some(coefficient) if matches!(**coefficient, Self::Sum(_)) => {
+                    f.write_fmt(format_args!("({coefficient})×{unit:?}"))
+                },
+                Some(coefficient) => f.write_fmt(format_args!("{coefficient}×{unit:?}")),
+                None => f.write_fmt(format_args!("{unit:?}")),
+            },
+            Self::Sum(terms) => {
+                for (i, term) in terms.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" + ")?;
+                    }
+
+                    Display::fmt(term, f)?;
+                }
+
+                Ok(())
+            },
+            Self::Abbreviated {
+                digit,
+                unit,
+            } => f.write_fmt(format_args!("{digit}×{unit:?}÷10")),
+        }
+    }
+}
+
+/// 將中文整數轉成語法樹。語法樹不會溢位，可以再依照需要計算數值。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_to_ast, ChineseCountMethod};
+///
+/// let ast = from_chinese_to_ast("三千二百萬零五", ChineseCountMethod::TenThousand).unwrap();
+///
+/// assert_eq!("(3×千 + 2×百)×萬 + 0 + 5", ast.to_string());
+/// assert_eq!(32000005, ast.to_u128(ChineseCountMethod::TenThousand).unwrap());
+///
+/// let ast = from_chinese_to_ast("一百二", ChineseCountMethod::TenThousand).unwrap();
+///
+/// assert_eq!("1×百 + 2×百÷10", ast.to_string());
+/// assert_eq!(120, ast.to_u128(ChineseCountMethod::TenThousand).unwrap());
+/// ```
+#[inline]
+pub fn from_chinese_to_ast<S: AsRef<str>>(
+    chinese_number: S,
+    method: ChineseCountMethod,
+) -> Result<ChineseNumberAst, ChineseToNumberError> {
+    chinese_to_unsigned(method, default_compound_unit(method), chinese_number.as_ref())
+}
@@ -1,17 +1,284 @@
+use alloc::string::{String, ToString};
 use core::{
     error::Error,
     fmt::{self, Display, Formatter},
+    ops::Range,
 };
 
+use crate::ChineseVariant;
+
+/// 錯誤在原始輸入中的位置。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ChineseNumberPosition {
+    /// 字元索引，空白字元也會被計算。
+    pub char_index: usize,
+    /// 位元組索引。
+    pub byte_index: usize,
+    /// 錯誤的字元。如果錯誤在輸入的結尾，則為 `None`。
+    pub char:       Option<char>,
+}
+
+impl ChineseNumberPosition {
+    #[inline]
+    pub(crate) fn locate(s: &str, byte_index: usize) -> Self {
+        Self {
+            char_index: s[..byte_index].chars().count(),
+            byte_index,
+            char: s[byte_index..].chars().next(),
+        }
+    }
+
+    /// 取得錯誤的字元在原始輸入中的位元組範圍。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::from_chinese_to_u32_ten_thousand;
+    ///
+    /// let position = from_chinese_to_u32_ten_thousand("一 百 X").unwrap_err().position().unwrap();
+    ///
+    /// assert_eq!(4, position.char_index);
+    /// assert_eq!(8..9, position.byte_range());
+    /// assert_eq!(Some('X'), position.char);
+    /// ```
+    #[inline]
+    pub fn byte_range(&self) -> Range<usize> {
+        self.byte_index..self.byte_index + self.char.map_or(0, char::len_utf8)
+    }
+}
+
+/// 在錯誤的位置應該出現的內容。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChineseNumberExpected {
+    /// 「一」到「九」之類的數字。
+    Digit,
+    /// 「十」、「百」、「萬」之類的單位。
+    Unit,
+    /// 「至」、「到」之類的範圍分隔符號。
+    RangeSeparator,
+}
+
 /// 將中文數字轉成數值時發生的錯誤。
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ChineseToNumberError {
     ChineseNumberEmpty,
-    ChineseNumberIncorrect { char_index: usize },
+    /// 在應該出現 `expected` 的位置出現了其它的字元，或是輸入提早結束。
+    ChineseNumberIncorrect {
+        position: ChineseNumberPosition,
+        expected: ChineseNumberExpected,
+    },
+    /// 無法辨識的字元。
+    UnknownCharacter {
+        position: ChineseNumberPosition,
+    },
+    /// 重複的單位，例如「一萬萬」。
+    DuplicateUnit {
+        position: ChineseNumberPosition,
+    },
+    /// 順序錯誤的單位，例如「五萬百」或「五釐三分」。
+    UnitOrder {
+        position: ChineseNumberPosition,
+    },
+    /// 不在開頭的正負號。
+    MisplacedSign {
+        position: ChineseNumberPosition,
+    },
+    /// 不能出現「零」的位置，例如「零分」。
+    MisplacedZero {
+        position: ChineseNumberPosition,
+    },
+    /// 不能出現在此處的小數單位，例如整數中的「角」、「分」或「釐」。
+    MisplacedFraction {
+        position: ChineseNumberPosition,
+    },
+    /// 解析選項不允許的寫法，例如嚴格模式下的「兩」或「一百二」。
+    NotAllowed {
+        position: ChineseNumberPosition,
+    },
+    /// 未啟用 `number-to-chinese` 特性，因此無法檢查寫法是否和 `to_chinese` 相同。
+    CanonicalCheckUnavailable,
     Overflow,
     Underflow,
 }
 
+impl ChineseToNumberError {
+    #[inline]
+    pub(crate) fn incorrect(s: &str, byte_index: usize, expected: ChineseNumberExpected) -> Self {
+        ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition::locate(s, byte_index),
+            expected,
+        }
+    }
+
+    /// 取得錯誤在原始輸入中的位置。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{from_chinese_to_u32_ten_thousand, ChineseToNumberError};
+    ///
+    /// let error = from_chinese_to_u32_ten_thousand("一萬萬").unwrap_err();
+    ///
+    /// assert!(matches!(error, ChineseToNumberError::DuplicateUnit { .. }));
+    /// assert_eq!(1, error.position().unwrap().char_index);
+    /// ```
+    #[inline]
+    pub const fn position(&self) -> Option<ChineseNumberPosition> {
+        match self {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                position, ..
+            }
+            | ChineseToNumberError::UnknownCharacter {
+                position,
+            }
+            | ChineseToNumberError::DuplicateUnit {
+                position,
+            }
+            | ChineseToNumberError::UnitOrder {
+                position,
+            }
+            | ChineseToNumberError::MisplacedSign {
+                position,
+            }
+            | ChineseToNumberError::MisplacedZero {
+                position,
+            }
+            | ChineseToNumberError::MisplacedFraction {
+                position,
+            }
+            | ChineseToNumberError::NotAllowed {
+                position,
+            } => Some(*position),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn with_position(self, position: ChineseNumberPosition) -> Self {
+        match self {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                expected, ..
+            } => ChineseToNumberError::ChineseNumberIncorrect {
+                position,
+                expected,
+            },
+            ChineseToNumberError::UnknownCharacter {
+                ..
+            } => ChineseToNumberError::UnknownCharacter {
+                position,
+            },
+            ChineseToNumberError::DuplicateUnit {
+                ..
+            } => ChineseToNumberError::DuplicateUnit {
+                position,
+            },
+            ChineseToNumberError::UnitOrder {
+                ..
+            } => ChineseToNumberError::UnitOrder {
+                position,
+            },
+            ChineseToNumberError::MisplacedSign {
+                ..
+            } => ChineseToNumberError::MisplacedSign {
+                position,
+            },
+            ChineseToNumberError::MisplacedZero {
+                ..
+            } => ChineseToNumberError::MisplacedZero {
+                position,
+            },
+            ChineseToNumberError::MisplacedFraction {
+                ..
+            } => ChineseToNumberError::MisplacedFraction {
+                position,
+            },
+            ChineseToNumberError::NotAllowed {
+                ..
+            } => ChineseToNumberError::NotAllowed {
+                position,
+            },
+            _ => self,
+        }
+    }
+
+    /// 取得以中文描述錯誤的 `Display` 實作。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{from_chinese_to_u32_ten_thousand, ChineseVariant};
+    ///
+    /// let error = from_chinese_to_u32_ten_thousand("一萬萬").unwrap_err();
+    ///
+    /// assert_eq!("單位重複（位置：1）", error.localized(ChineseVariant::Traditional).to_string());
+    /// assert_eq!("单位重复（位置：1）", error.localized(ChineseVariant::Simple).to_string());
+    /// ```
+    #[inline]
+    pub const fn localized(self, chinese_variant: ChineseVariant) -> LocalizedChineseToNumberError {
+        LocalizedChineseToNumberError {
+            error: self,
+            chinese_variant,
+        }
+    }
+
+    /// 將錯誤以中文描述。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseToNumberError, ChineseVariant};
+    ///
+    /// assert_eq!(
+    ///     "中文數字不能為空",
+    ///     ChineseToNumberError::ChineseNumberEmpty
+    ///         .to_localized_string(ChineseVariant::Traditional)
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_localized_string(self, chinese_variant: ChineseVariant) -> String {
+        self.localized(chinese_variant).to_string()
+    }
+
+    #[inline]
+    pub(crate) fn relocate(self, s: &str, offset: usize) -> Self {
+        match self.position() {
+            Some(position) => {
+                self.with_position(ChineseNumberPosition::locate(s, position.byte_index + offset))
+            },
+            None => self,
+        }
+    }
+}
+
+impl ChineseNumberExpected {
+    #[inline]
+    const fn to_localized_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match chinese_variant {
+            ChineseVariant::Traditional => match self {
+                ChineseNumberExpected::Digit => "數字",
+                ChineseNumberExpected::Unit => "單位",
+                ChineseNumberExpected::RangeSeparator => "範圍分隔符號",
+            },
+            ChineseVariant::Simple => match self {
+                ChineseNumberExpected::Digit => "数字",
+                ChineseNumberExpected::Unit => "单位",
+                ChineseNumberExpected::RangeSeparator => "范围分隔符号",
+            },
+        }
+    }
+}
+
+impl Display for ChineseNumberExpected {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ChineseNumberExpected::Digit => f.write_str("a digit"),
+            ChineseNumberExpected::Unit => f.write_str("a unit"),
+            ChineseNumberExpected::RangeSeparator => f.write_str("a range separator"),
+        }
+    }
+}
+
 impl Display for ChineseToNumberError {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
@@ -20,11 +287,65 @@ impl Display for ChineseToNumberError {
                 f.write_str("a chinese number cannot be empty")
             },
             ChineseToNumberError::ChineseNumberIncorrect {
-                char_index,
+                position,
+                expected,
+            } => match position.char {
+                Some(c) => f.write_fmt(format_args!(
+                    "the chinese number is incorrect at {:?}, expected {} (position: {})",
+                    c, expected, position.char_index
+                )),
+                None => f.write_fmt(format_args!(
+                    "the chinese number is incomplete, expected {} (position: {})",
+                    expected, position.char_index
+                )),
+            },
+            ChineseToNumberError::UnknownCharacter {
+                position,
+            } => f.write_fmt(format_args!(
+                "{:?} is not a part of a chinese number (position: {})",
+                position.char.unwrap_or_default(),
+                position.char_index
+            )),
+            ChineseToNumberError::DuplicateUnit {
+                position,
             } => f.write_fmt(format_args!(
-                "the chinese number is incorrect (position: {})",
-                char_index
+                "the unit is repeated (position: {})",
+                position.char_index
             )),
+            ChineseToNumberError::UnitOrder {
+                position,
+            } => f.write_fmt(format_args!(
+                "the unit is out of order (position: {})",
+                position.char_index
+            )),
+            ChineseToNumberError::MisplacedSign {
+                position,
+            } => f.write_fmt(format_args!(
+                "a sign can only be at the beginning (position: {})",
+                position.char_index
+            )),
+            ChineseToNumberError::MisplacedZero {
+                position,
+            } => f.write_fmt(format_args!(
+                "a zero cannot be here (position: {})",
+                position.char_index
+            )),
+            ChineseToNumberError::MisplacedFraction {
+                position,
+            } => f.write_fmt(format_args!(
+                "a fraction unit cannot be here (position: {})",
+                position.char_index
+            )),
+            ChineseToNumberError::NotAllowed {
+                position,
+            } => f.write_fmt(format_args!(
+                "{:?} is not allowed by the parse options (position: {})",
+                position.char.unwrap_or_default(),
+                position.char_index
+            )),
+            ChineseToNumberError::CanonicalCheckUnavailable => {
+                f.write_str("checking the canonical form requires the number-to-chinese feature")
+            },
             ChineseToNumberError::Overflow => f.write_str("number is too large"),
             ChineseToNumberError::Underflow => f.write_str("number is too small"),
         }
@@ -32,3 +353,130 @@ impl Display for ChineseToNumberError {
 }
 
 impl Error for ChineseToNumberError {}
+
+/// 以中文描述 `ChineseToNumberError` 的 `Display` 實作。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LocalizedChineseToNumberError {
+    error:           ChineseToNumberError,
+    chinese_variant: ChineseVariant,
+}
+
+impl Display for LocalizedChineseToNumberError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self.chinese_variant {
+            ChineseVariant::Traditional => match self.error {
+                ChineseToNumberError::ChineseNumberEmpty => f.write_str("中文數字不能為空"),
+                ChineseToNumberError::ChineseNumberIncorrect {
+                    position,
+                    expected,
+                } => match position.char {
+                    Some(c) => f.write_fmt(format_args!(
+                        "中文數字在「{}」處不正確，應為{}（位置：{}）",
+                        c,
+                        expected.to_localized_str(self.chinese_variant),
+                        position.char_index
+                    )),
+                    None => f.write_fmt(format_args!(
+                        "中文數字不完整，應為{}（位置：{}）",
+                        expected.to_localized_str(self.chinese_variant),
+                        position.char_index
+                    )),
+                },
+                ChineseToNumberError::UnknownCharacter {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "「{}」不是中文數字的一部分（位置：{}）",
+                    position.char.unwrap_or_default(),
+                    position.char_index
+                )),
+                ChineseToNumberError::DuplicateUnit {
+                    position,
+                } => f.write_fmt(format_args!("單位重複（位置：{}）", position.char_index)),
+                ChineseToNumberError::UnitOrder {
+                    position,
+                } => f.write_fmt(format_args!("單位順序錯誤（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedSign {
+                    position,
+                } => f.write_fmt(format_args!("正負號只能在開頭（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedZero {
+                    position,
+                } => f.write_fmt(format_args!("此處不能有「零」（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedFraction {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "此處不能有小數單位（位置：{}）",
+                    position.char_index
+                )),
+                ChineseToNumberError::NotAllowed {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "「{}」不符合解析選項（位置：{}）",
+                    position.char.unwrap_or_default(),
+                    position.char_index
+                )),
+                ChineseToNumberError::CanonicalCheckUnavailable => {
+                    f.write_str("需要 number-to-chinese 特性才能檢查標準寫法")
+                },
+                ChineseToNumberError::Overflow => f.write_str("數值太大"),
+                ChineseToNumberError::Underflow => f.write_str("數值太小"),
+            },
+            ChineseVariant::Simple => match self.error {
+                ChineseToNumberError::ChineseNumberEmpty => f.write_str("中文数字不能为空"),
+                ChineseToNumberError::ChineseNumberIncorrect {
+                    position,
+                    expected,
+                } => match position.char {
+                    Some(c) => f.write_fmt(format_args!(
+                        "中文数字在“{}”处不正确，应为{}（位置：{}）",
+                        c,
+                        expected.to_localized_str(self.chinese_variant),
+                        position.char_index
+                    )),
+                    None => f.write_fmt(format_args!(
+                        "中文数字不完整，应为{}（位置：{}）",
+                        expected.to_localized_str(self.chinese_variant),
+                        position.char_index
+                    )),
+                },
+                ChineseToNumberError::UnknownCharacter {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "“{}”不是中文数字的一部分（位置：{}）",
+                    position.char.unwrap_or_default(),
+                    position.char_index
+                )),
+                ChineseToNumberError::DuplicateUnit {
+                    position,
+                } => f.write_fmt(format_args!("单位重复（位置：{}）", position.char_index)),
+                ChineseToNumberError::UnitOrder {
+                    position,
+                } => f.write_fmt(format_args!("单位顺序错误（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedSign {
+                    position,
+                } => f.write_fmt(format_args!("正负号只能在开头（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedZero {
+                    position,
+                } => f.write_fmt(format_args!("此处不能有“零”（位置：{}）", position.char_index)),
+                ChineseToNumberError::MisplacedFraction {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "此处不能有小数单位（位置：{}）",
+                    position.char_index
+                )),
+                ChineseToNumberError::NotAllowed {
+                    position,
+                } => f.write_fmt(format_args!(
+                    "“{}”不符合解析选项（位置：{}）",
+                    position.char.unwrap_or_default(),
+                    position.char_index
+                )),
+                ChineseToNumberError::CanonicalCheckUnavailable => {
+                    f.write_str("需要 number-to-chinese 特性才能检查标准写法")
+                },
+                ChineseToNumberError::Overflow => f.write_str("数值太大"),
+                ChineseToNumberError::Underflow => f.write_str("数值太小"),
+            },
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use super::scanner::{run_boundaries, unit_at};
+use crate::{ChineseCountMethod, chinese_characters::ChineseExponent};
+
+/// 推測出的算術類型。
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChineseCountMethodGuess {
+    /// 最有可能的算術類型。
+    pub method:     ChineseCountMethod,
+    /// 信心程度，介於 `0.0` 和 `1.0` 之間。
+    pub confidence: f64,
+}
+
+/// 從文字中的中文數字推測所使用的算術類型。可以陸續加入多段文字，再一起推測。
+///
+/// 只有各算術類型寫法不同的地方會被當作依據，例如：
+///
+/// * 「萬億」之類以「萬」開頭的複合單位只會出現在 **「中數」** 和 **「上數」**，其中「萬億」也會出現在 **「大陸用法」**。
+/// * 「億兆」之類以「億」以上的單位開頭的複合單位只會出現在 **「上數」**，「億億」則會出現在 **「上數」** 和 **「大陸用法」**。
+/// * 「十二萬」之類在大單位前面有多位數的寫法不會出現在 **「下數」**。
+/// * 有「兆」以上的單位卻沒有複合單位的數字，通常是 **「萬進」**。
+/// * 每個大單位前面都只有一位數字的數字，例如「一億二萬」，通常是 **「下數」**。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseCountMethod, ChineseCountMethodDetector};
+///
+/// let mut detector = ChineseCountMethodDetector::new();
+///
+/// detector.feed("國家預算為三兆五千億元。");
+///
+/// assert_eq!(ChineseCountMethod::TenThousand, detector.guess().unwrap().method);
+///
+/// detector.feed("又聞三萬億之數，其後有一億兆。");
+///
+/// let guess = detector.guess().unwrap();
+///
+/// assert_eq!(ChineseCountMethod::High, guess.method);
+/// assert!(guess.confidence < 1.0);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ChineseCountMethodDetector {
+    low:          u32,
+    ten_thousand: u32,
+    middle:       u32,
+    high:         u32,
+    mainland:     u32,
+}
+
+impl ChineseCountMethodDetector {
+    /// 建立沒有任何依據的推測器。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            low:          0,
+            ten_thousand: 0,
+            middle:       0,
+            high:         0,
+            mainland:     0,
+        }
+    }
+
+    /// 加入一段文字。文字中的每個中文數字都會被分開分析。
+    pub fn feed<S: AsRef<str>>(&mut self, text: S) {
+        let text = text.as_ref();
+
+        let mut index = 0;
+
+        while let Some(c) = text[index..].chars().next() {
+            let boundaries = run_boundaries(text, index, false);
+
+            if boundaries.is_empty() {
+                index += c.len_utf8();
+
+                continue;
+            }
+
+            let mut run = RunAnalysis::default();
+
+            for end in boundaries {
+                match unit_at(text, index) {
+                    Some((exp, unit_end)) if unit_end == end => run.push_unit(exp),
+                    _ => run.push_digit(),
+                }
+
+                index = end;
+            }
+
+            self.add(&run);
+        }
+    }
+
+    /// 推測最有可能的算術類型。如果沒有任何依據，則回傳 `None`。分數相同時，依序優先選擇 **「萬進」**、**「大陸用法」**、**「中數」**、**「上數」**、**「下數」**。
+    pub fn guess(&self) -> Option<ChineseCountMethodGuess> {
+        let total = self.low + self.ten_thousand + self.middle + self.high + self.mainland;
+
+        if total == 0 {
+            return None;
+        }
+
+        let (method, score) = [
+            (ChineseCountMethod::TenThousand, self.ten_thousand),
+            (ChineseCountMethod::Mainland, self.mainland),
+            (ChineseCountMethod::Middle, self.middle),
+            (ChineseCountMethod::High, self.high),
+            (ChineseCountMethod::Low, self.low),
+        ]
+        .into_iter()
+        .fold((ChineseCountMethod::TenThousand, 0), |best, candidate| {
+            if candidate.1 > best.1 { candidate } else { best }
+        });
+
+        Some(ChineseCountMethodGuess {
+            method,
+            confidence: score as f64 / total as f64,
+        })
+    }
+
+    fn add(&mut self, run: &RunAnalysis) {
+        if run.ascending_compound {
+            self.high += 2;
+        }
+
+        if run.repeated_compound {
+            self.high += 1;
+        }
+
+        if run.ten_thousand_compound {
+            self.middle += 1;
+            self.high += 1;
+        }
+
+        if run.mainland_compound {
+            self.mainland += 1;
+        }
+
+        if run.long_coefficient {
+            self.ten_thousand += 1;
+            self.middle += 1;
+            self.high += 1;
+            self.mainland += 1;
+        }
+
+        let compound = run.ascending_compound || run.repeated_compound || run.ten_thousand_compound;
+
+        if run.largest_unit >= Some(ChineseExponent::兆) && run.long_coefficient && !compound {
+            self.ten_thousand += 1;
+        }
+
+        if run.large_units >= 2 && !run.small_unit && !compound {
+            self.low += 1;
+        }
+    }
+}
+
+/// 推測一段文字中的中文數字所使用的算術類型。如果沒有任何依據，則回傳 `None`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{detect_count_method, ChineseCountMethod};
+///
+/// assert_eq!(ChineseCountMethod::Mainland, detect_count_method("一萬億").unwrap().method);
+/// assert_eq!(ChineseCountMethod::Middle, detect_count_method("一萬兆").unwrap().method);
+/// assert_eq!(ChineseCountMethod::Low, detect_count_method("一極二載三正").unwrap().method);
+/// assert_eq!(None, detect_count_method("三百二十一"));
+/// ```
+#[inline]
+pub fn detect_count_method<S: AsRef<str>>(text: S) -> Option<ChineseCountMethodGuess> {
+    let mut detector = ChineseCountMethodDetector::new();
+
+    detector.feed(text);
+
+    detector.guess()
+}
+
+#[derive(Debug, Default)]
+struct RunAnalysis {
+    previous_large_unit:   Option<ChineseExponent>,
+    small_unit_since_last: bool,
+    largest_unit:          Option<ChineseExponent>,
+    large_units:           usize,
+    small_unit:            bool,
+    ten_thousand_compound: bool,
+    ascending_compound:    bool,
+    repeated_compound:     bool,
+    mainland_compound:     bool,
+    long_coefficient:      bool,
+}
+
+impl RunAnalysis {
+    fn push_digit(&mut self) {
+        self.previous_large_unit = None;
+    }
+
+    fn push_unit(&mut self, exp: ChineseExponent) {
+        if exp < ChineseExponent::萬 {
+            self.previous_large_unit = None;
+
+            if exp > ChineseExponent::個 {
+                self.small_unit_since_last = true;
+                self.small_unit = true;
+            }
+
+            return;
+        }
+
+        match self.previous_large_unit {
+            Some(ChineseExponent::萬) if exp > ChineseExponent::萬 => {
+                self.ten_thousand_compound = true;
+                self.mainland_compound |= exp == ChineseExponent::億;
+            },
+            Some(previous) if previous >= ChineseExponent::億 && exp > previous => {
+                self.ascending_compound = true;
+            },
+            Some(previous) if previous >= ChineseExponent::億 && exp == previous => {
+                self.repeated_compound = true;
+                self.mainland_compound |= exp == ChineseExponent::億;
+            },
+            Some(_) => (),
+            None => {
+                if self.small_unit_since_last {
+                    self.long_coefficient = true;
+                }
+            },
+        }
+
+        self.previous_large_unit = Some(exp);
+        self.small_unit_since_last = false;
+        self.largest_unit = self.largest_unit.max(Some(exp));
+        self.large_units += 1;
+    }
+}
@@ -1,71 +1,260 @@
-use alloc::vec::Vec;
-use core::cmp::Ordering;
+use core::{cmp::Ordering, ops::Div};
 
+#[cfg(feature = "number-to-chinese")]
+use num_bigint::BigUint;
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
+use num_traits::{CheckedAdd, CheckedMul, One, Zero, checked_pow};
 
 use crate::{
-    ChineseCountMethod, ChineseExponent, ChineseNumber, ChineseSign, ChineseToNumberError,
+    ChineseCountMethod, ChineseDecimalUnit, ChineseExponent, ChineseNumber, ChineseNumberExpected,
+    ChineseNumberPosition, ChinesePoint, ChineseSign, ChineseToNumberError,
 };
 
 #[inline]
-pub(crate) fn to_chars_vec<S: AsRef<str>>(s: S) -> Vec<char> {
-    s.as_ref().chars().filter(|c| !c.is_whitespace()).collect()
+pub(crate) fn char_before(s: &str, end: usize) -> Option<(usize, char)> {
+    s[..end].char_indices().rev().find(|(_, c)| !c.is_whitespace())
 }
 
-fn get_exp_base(
+#[inline]
+pub(crate) fn char_after(s: &str, start: usize) -> Option<(usize, char)> {
+    s[start..].char_indices().find(|(_, c)| !c.is_whitespace()).map(|(i, c)| (start + i, c))
+}
+
+#[inline]
+pub(crate) fn exponent_at(s: &str, index: usize) -> Option<(ChineseExponent, usize)> {
+    let end = index + s[index..].chars().next()?.len_utf8();
+
+    let mut chars = s[..end].char_indices().rev().filter(|(_, c)| !c.is_whitespace());
+
+    let (exp, length) = ChineseExponent::from_chars_rev(chars.clone().map(|(_, c)| c))?;
+
+    chars.nth(length - 1).map(|(start, _)| (exp, start))
+}
+
+#[inline]
+pub(crate) fn split_sign(s: &str) -> Option<(ChineseSign, usize)> {
+    let (index, c) = char_after(s, 0)?;
+
+    match ChineseSign::from_char(c) {
+        Some(sign) => Some((sign, index + c.len_utf8())),
+        None => Some((ChineseSign::正, 0)),
+    }
+}
+
+pub(crate) fn unexpected(
+    s: &str,
+    index: usize,
+    expected: ChineseNumberExpected,
+) -> ChineseToNumberError {
+    let position = ChineseNumberPosition::locate(s, index);
+
+    let c = match position.char {
+        Some(c) => c,
+        None => {
+            return ChineseToNumberError::ChineseNumberIncorrect {
+                position,
+                expected,
+            };
+        },
+    };
+
+    if ChineseSign::from_char(c).is_some() {
+        ChineseToNumberError::MisplacedSign {
+            position,
+        }
+    } else if ChineseNumber::from_char(c) == Some(ChineseNumber::零) {
+        ChineseToNumberError::MisplacedZero {
+            position,
+        }
+    } else if ChineseDecimalUnit::from_char(c).is_some()
+        || matches!(ChineseExponent::from_char(c), Some(exp) if exp < ChineseExponent::個)
+    {
+        ChineseToNumberError::MisplacedFraction {
+            position,
+        }
+    } else if ChineseNumber::from_char(c).is_some()
+        || ChineseExponent::from_chars_rev(s[..index + c.len_utf8()].chars().rev()).is_some()
+        || ChinesePoint::from_char(c).is_some()
+    {
+        ChineseToNumberError::ChineseNumberIncorrect {
+            position,
+            expected,
+        }
+    } else {
+        ChineseToNumberError::UnknownCharacter {
+            position,
+        }
+    }
+}
+
+fn misplaced_unit(
+    s: &str,
+    start: usize,
+    exp: ChineseExponent,
+    level: ChineseExponent,
+) -> ChineseToNumberError {
+    let position = ChineseNumberPosition::locate(s, start);
+
+    if exp < ChineseExponent::個 {
+        ChineseToNumberError::MisplacedFraction {
+            position,
+        }
+    } else if char_before(s, start).is_none() {
+        ChineseToNumberError::ChineseNumberIncorrect {
+            position,
+            expected: ChineseNumberExpected::Digit,
+        }
+    } else if exp == level {
+        ChineseToNumberError::DuplicateUnit {
+            position,
+        }
+    } else {
+        ChineseToNumberError::UnitOrder {
+            position,
+        }
+    }
+}
+
+pub(crate) trait UnsignedInteger:
+    Clone + Zero + One + CheckedAdd + CheckedMul + Div<Output = Self> + From<u32> {
+}
+
+impl<T: Clone + Zero + One + CheckedAdd + CheckedMul + Div<Output = T> + From<u32>> UnsignedInteger
+    for T
+{
+}
+
+pub(crate) trait ParsedInteger: Sized {
+    type Unit;
+
+    fn unit(
+        method: ChineseCountMethod,
+        exp: ChineseExponent,
+    ) -> Result<Self::Unit, ChineseToNumberError>;
+
+    fn digit(n: ChineseNumber) -> Self;
+
+    fn implicit_ten() -> Self;
+
+    fn empty() -> Self;
+
+    fn scale(self, unit: &Self::Unit) -> Result<Self, ChineseToNumberError>;
+
+    fn add_higher(self, higher: Self) -> Result<Self, ChineseToNumberError>;
+
+    fn abbreviate(self) -> Self;
+}
+
+impl<T: UnsignedInteger> ParsedInteger for T {
+    type Unit = T;
+
+    #[inline]
+    fn unit(method: ChineseCountMethod, exp: ChineseExponent) -> Result<T, ChineseToNumberError> {
+        get_exp_base(method, exp)
+    }
+
+    #[inline]
+    fn digit(n: ChineseNumber) -> Self {
+        T::from(n.ordinal() as u32)
+    }
+
+    #[inline]
+    fn implicit_ten() -> Self {
+        T::from(10)
+    }
+
+    #[inline]
+    fn empty() -> Self {
+        T::zero()
+    }
+
+    #[inline]
+    fn scale(self, unit: &T) -> Result<Self, ChineseToNumberError> {
+        self.checked_mul(unit).ok_or(ChineseToNumberError::Overflow)
+    }
+
+    #[inline]
+    fn add_higher(self, higher: Self) -> Result<Self, ChineseToNumberError> {
+        self.checked_add(&higher).ok_or(ChineseToNumberError::Overflow)
+    }
+
+    #[inline]
+    fn abbreviate(self) -> Self {
+        self / T::from(10)
+    }
+}
+
+#[inline]
+fn with_zeros<T: ParsedInteger>(digit: T, zeros: usize) -> Result<T, ChineseToNumberError> {
+    (0..zeros).try_fold(digit, |value, _| value.add_higher(T::digit(ChineseNumber::零)))
+}
+
+pub(crate) fn get_exp_base<T: UnsignedInteger>(
     method: ChineseCountMethod,
     exp: ChineseExponent,
-) -> Result<u128, ChineseToNumberError> {
+) -> Result<T, ChineseToNumberError> {
     match method {
         ChineseCountMethod::Low => match exp {
-            ChineseExponent::個 => Ok(1),
+            ChineseExponent::個 => Ok(T::one()),
             _ => {
                 debug_assert!(exp > ChineseExponent::個);
 
-                Ok(10u128.pow((exp.ordinal() - ChineseExponent::個.ordinal()) as u32))
+                checked_pow(
+                    T::from(10),
+                    (exp.ordinal() - ChineseExponent::個.ordinal()) as usize,
+                )
+                .ok_or(ChineseToNumberError::Overflow)
             },
         },
         ChineseCountMethod::TenThousand => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
+            ChineseExponent::個 => Ok(T::one()),
+            ChineseExponent::十 => Ok(T::from(10)),
+            ChineseExponent::百 => Ok(T::from(100)),
+            ChineseExponent::千 => Ok(T::from(1000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::千);
 
-                1_0000u128
-                    .checked_pow((exp.ordinal() - ChineseExponent::千.ordinal()) as u32)
-                    .ok_or(ChineseToNumberError::Overflow)
+                checked_pow(
+                    T::from(1_0000),
+                    (exp.ordinal() - ChineseExponent::千.ordinal()) as usize,
+                )
+                .ok_or(ChineseToNumberError::Overflow)
             },
         },
         ChineseCountMethod::Middle => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
-            ChineseExponent::萬 => Ok(1_0000),
+            ChineseExponent::個 => Ok(T::one()),
+            ChineseExponent::十 => Ok(T::from(10)),
+            ChineseExponent::百 => Ok(T::from(100)),
+            ChineseExponent::千 => Ok(T::from(1000)),
+            ChineseExponent::萬 => Ok(T::from(1_0000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::萬);
 
-                1_0000_0000u128
-                    .checked_pow((exp.ordinal() - ChineseExponent::萬.ordinal()) as u32)
-                    .ok_or(ChineseToNumberError::Overflow)
+                checked_pow(
+                    T::from(1_0000_0000),
+                    (exp.ordinal() - ChineseExponent::萬.ordinal()) as usize,
+                )
+                .ok_or(ChineseToNumberError::Overflow)
             },
         },
+        ChineseCountMethod::Mainland => match exp {
+            ChineseExponent::兆 => Ok(T::from(100_0000)),
+            _ => get_exp_base(ChineseCountMethod::TenThousand, exp),
+        },
         ChineseCountMethod::High => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
+            ChineseExponent::個 => Ok(T::one()),
+            ChineseExponent::十 => Ok(T::from(10)),
+            ChineseExponent::百 => Ok(T::from(100)),
+            ChineseExponent::千 => Ok(T::from(1000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::千);
 
-                let mut w = 1_0000u128;
+                let mut w = T::from(1_0000);
 
                 for _ in 0..exp.ordinal() - ChineseExponent::萬.ordinal() {
-                    w = w.checked_pow(2).ok_or(ChineseToNumberError::Overflow)?;
+                    w = w.checked_mul(&w).ok_or(ChineseToNumberError::Overflow)?;
                 }
 
                 Ok(w)
@@ -74,98 +263,133 @@ fn get_exp_base(
     }
 }
 
-pub(crate) fn chinese_to_unsigned_integer_unit(
+#[inline]
+pub(crate) const fn default_compound_unit(method: ChineseCountMethod) -> Option<ChineseExponent> {
+    match method {
+        ChineseCountMethod::Mainland => Some(ChineseExponent::億),
+        _ => None,
+    }
+}
+
+fn compare_unit(
+    method: ChineseCountMethod,
+    compound_unit: Option<ChineseExponent>,
+    exp: ChineseExponent,
+    level: ChineseExponent,
+) -> Ordering {
+    if exp == level && compound_unit == Some(exp) {
+        return Ordering::Less;
+    }
+
+    match method {
+        ChineseCountMethod::Mainland => {
+            let rank = |exp: ChineseExponent| match exp {
+                ChineseExponent::兆 => ChineseExponent::萬.ordinal() * 2 + 1,
+                _ => exp.ordinal() * 2,
+            };
+
+            rank(exp).cmp(&rank(level))
+        },
+        _ => exp.cmp(&level),
+    }
+}
+
+fn chinese_to_unsigned_integer_unit<T: ParsedInteger>(
     method: ChineseCountMethod,
-    chars: &[char],
-    mut pointer: usize,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+    end: usize,
     level: ChineseExponent,
-) -> Result<(u128, Option<(usize, ChineseExponent)>), ChineseToNumberError> {
-    debug_assert!(!chars.is_empty() && pointer < chars.len());
+) -> Result<(T, Option<(usize, ChineseExponent)>), ChineseToNumberError> {
+    let (mut pointer, c) = char_before(s, end).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
-    let base = get_exp_base(method, level)?;
+    let base = T::unit(method, level)?;
 
-    let (n, exp) = match ChineseNumber::from_char(chars[pointer]) {
-        Some(n) if n == ChineseNumber::十 => {
-            if pointer == 0 {
-                return Ok((
-                    (n.ordinal() as u128)
-                        .checked_mul(base)
-                        .ok_or(ChineseToNumberError::Overflow)?,
-                    None,
-                ));
+    let (n, exp) = match ChineseNumber::from_char(c) {
+        Some(ChineseNumber::十) => {
+            if char_before(s, pointer).is_none() {
+                return Ok((T::implicit_ten().scale(&base)?, None));
             }
 
-            (0u128, ChineseExponent::十)
+            (T::empty(), ChineseExponent::十)
         },
         Some(n) => {
-            if pointer == 0 {
-                return Ok((
-                    (n.ordinal() as u128)
-                        .checked_mul(base)
-                        .ok_or(ChineseToNumberError::Overflow)?,
-                    None,
-                ));
-            }
+            let (mut pointer_before, mut c) = match char_before(s, pointer) {
+                Some(pair) => pair,
+                None => {
+                    return Ok((T::digit(n).scale(&base)?, None));
+                },
+            };
 
-            pointer -= 1;
+            let mut zeros = 0;
 
             loop {
-                match ChineseExponent::from_char(chars[pointer]) {
-                    Some(exp) if exp > ChineseExponent::個 => {
-                        if pointer == 0 {
+                match exponent_at(s, pointer_before) {
+                    Some((exp, start)) if exp > ChineseExponent::個 => {
+                        if char_before(s, start).is_none() {
                             if exp == ChineseExponent::十 {
                                 return Ok((
-                                    ((10 + n.ordinal()) as u128)
-                                        .checked_mul(base)
-                                        .ok_or(ChineseToNumberError::Overflow)?,
+                                    with_zeros(T::digit(n), zeros)?
+                                        .add_higher(T::implicit_ten())?
+                                        .scale(&base)?,
                                     None,
                                 ));
                             } else {
-                                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                                    char_index: pointer,
-                                });
+                                return Err(ChineseToNumberError::incorrect(
+                                    s,
+                                    start,
+                                    ChineseNumberExpected::Digit,
+                                ));
                             }
                         }
 
-                        break (n.ordinal() as u128, exp);
+                        pointer = start;
+
+                        break (with_zeros(T::digit(n), zeros)?, exp);
                     },
-                    _ => match ChineseNumber::from_char(chars[pointer]) {
+                    _ => match ChineseNumber::from_char(c) {
                         Some(ChineseNumber::零) => {
-                            if pointer == 0 {
-                                return Ok((
-                                    (n.ordinal() as u128)
-                                        .checked_mul(base)
-                                        .ok_or(ChineseToNumberError::Overflow)?,
-                                    None,
-                                ));
+                            zeros += 1;
+
+                            match char_before(s, pointer_before) {
+                                Some(pair) => (pointer_before, c) = pair,
+                                None => {
+                                    return Ok((
+                                        with_zeros(T::digit(n), zeros)?.scale(&base)?,
+                                        None,
+                                    ));
+                                },
                             }
 
-                            pointer -= 1;
-
                             continue;
                         },
                         _ => {
-                            return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                                char_index: pointer,
-                            });
+                            return Err(unexpected(s, pointer_before, ChineseNumberExpected::Unit));
                         },
                     },
                 }
             }
         },
         _ => {
-            if pointer == 0 {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: pointer
-                });
+            if char_before(s, pointer).is_none() {
+                return Err(unexpected(s, pointer, ChineseNumberExpected::Digit));
             }
 
-            match ChineseExponent::from_char(chars[pointer]) {
-                Some(exp) if exp < level => (0u128, exp),
-                _ => {
-                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                        char_index: pointer,
-                    });
+            match exponent_at(s, pointer) {
+                Some((exp, start))
+                    if exp > ChineseExponent::個
+                        && compare_unit(method, compound_unit, exp, level).is_lt()
+                        && char_before(s, start).is_some() =>
+                {
+                    pointer = start;
+
+                    (T::empty(), exp)
+                },
+                Some((exp, start)) => {
+                    return Err(misplaced_unit(s, start, exp, level));
+                },
+                None => {
+                    return Err(unexpected(s, pointer, ChineseNumberExpected::Digit));
                 },
             }
         },
@@ -175,105 +399,126 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
     let mut next = Some((pointer, exp));
 
     while let Some((pointer, exp)) = next {
-        match exp.cmp(&level) {
+        match compare_unit(method, compound_unit, exp, level) {
             Ordering::Greater => {
                 break;
             },
             Ordering::Less => {
-                let result = chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
+                let result =
+                    chinese_to_unsigned_integer_unit::<T>(method, compound_unit, s, pointer, exp)?;
 
-                sum = sum.checked_add(result.0).ok_or(ChineseToNumberError::Overflow)?;
+                sum = sum.add_higher(result.0)?;
 
                 next = result.1;
             },
             Ordering::Equal => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: pointer
+                return Err(ChineseToNumberError::DuplicateUnit {
+                    position: ChineseNumberPosition::locate(s, pointer),
                 });
             },
         }
     }
 
-    sum = sum.checked_mul(base).ok_or(ChineseToNumberError::Overflow)?;
+    sum = sum.scale(&base)?;
 
     Ok((sum, next))
 }
 
+#[inline]
 pub(crate) fn chinese_to_unsigned_integer(
     method: ChineseCountMethod,
-    chars: &[char],
+    s: &str,
 ) -> Result<u128, ChineseToNumberError> {
-    let length = chars.len();
+    chinese_to_unsigned(method, default_compound_unit(method), s)
+}
 
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
-    }
+#[inline]
+pub(crate) fn chinese_to_unsigned_integer_compound(
+    method: ChineseCountMethod,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+) -> Result<u128, ChineseToNumberError> {
+    chinese_to_unsigned(method, compound_unit, s)
+}
 
-    let mut pointer = length - 1;
+#[cfg(feature = "number-to-chinese")]
+#[inline]
+pub(crate) fn chinese_to_big_unsigned_integer(
+    method: ChineseCountMethod,
+    s: &str,
+) -> Result<BigUint, ChineseToNumberError> {
+    chinese_to_unsigned(method, default_compound_unit(method), s)
+}
 
-    let mut exp = match ChineseExponent::from_char(chars[pointer]) {
-        Some(exp) if exp > ChineseExponent::個 => {
-            if pointer == 0 {
+pub(crate) fn chinese_to_unsigned<T: ParsedInteger>(
+    method: ChineseCountMethod,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+) -> Result<T, ChineseToNumberError> {
+    let (last, _) = char_before(s, s.len()).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
+
+    let (mut end, mut exp) = match exponent_at(s, last) {
+        Some((exp, start)) if exp > ChineseExponent::個 => {
+            if char_before(s, start).is_none() {
                 if exp == ChineseExponent::十 {
-                    return Ok(10);
+                    return Ok(T::implicit_ten());
                 } else {
-                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                        char_index: pointer,
-                    });
+                    return Err(ChineseToNumberError::incorrect(
+                        s,
+                        start,
+                        ChineseNumberExpected::Digit,
+                    ));
                 }
             }
 
-            exp
+            (start, exp)
         },
         _ => {
-            if length >= 3 {
-                // look ahead
-
-                let left_char = chars[pointer - 1];
-
+            // look ahead, which needs at least two characters before the last one
+            if let Some((left, left_char)) = char_before(s, last)
+                && char_before(s, left).is_some()
+            {
                 if let Some(ChineseNumber::零) = ChineseNumber::from_char(left_char) {
                     // do nothing
                 } else {
-                    match ChineseExponent::from_char(left_char) {
-                        Some(exp) if exp >= ChineseExponent::百 => {
-                            let high = chinese_to_unsigned_integer(method, &chars[..pointer])?;
-
-                            let low =
-                                chinese_to_unsigned_integer_unit(method, &chars[pointer..], 0, exp)
-                                    .map_err(|mut err| {
-                                        if let ChineseToNumberError::ChineseNumberIncorrect {
-                                            char_index,
-                                        } = &mut err
-                                        {
-                                            *char_index = pointer;
-                                        }
-
-                                        err
-                                    })?
-                                    .0
-                                    / 10;
-
-                            return high.checked_add(low).ok_or(ChineseToNumberError::Overflow);
+                    match exponent_at(s, left) {
+                        Some((exp, _)) if exp >= ChineseExponent::百 => {
+                            let high = chinese_to_unsigned::<T>(method, compound_unit, &s[..last])?;
+
+                            let low = chinese_to_unsigned_integer_unit::<T>(
+                                method,
+                                compound_unit,
+                                &s[last..],
+                                s.len() - last,
+                                exp,
+                            )
+                            .map_err(|err| match err.position() {
+                                Some(_) => unexpected(s, last, ChineseNumberExpected::Digit),
+                                None => err,
+                            })?
+                            .0
+                            .abbreviate();
+
+                            return low.add_higher(high);
                         },
                         _ => (),
                     }
                 }
             }
 
-            pointer += 1;
-            ChineseExponent::個
+            (s.len(), ChineseExponent::個)
         },
     };
 
-    let mut sum = 0u128;
+    let mut sum = T::empty();
 
     loop {
-        let result = chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
+        let result = chinese_to_unsigned_integer_unit::<T>(method, compound_unit, s, end, exp)?;
 
-        sum = sum.checked_add(result.0).ok_or(ChineseToNumberError::Overflow)?;
+        sum = sum.add_higher(result.0)?;
 
         if let Some((p, e)) = result.1 {
-            pointer = p;
+            end = p;
             exp = e;
 
             continue;
@@ -285,36 +530,29 @@ pub(crate) fn chinese_to_unsigned_integer(
     Ok(sum)
 }
 
+#[inline]
 pub(crate) fn chinese_to_signed_integer(
     method: ChineseCountMethod,
-    chars: &[char],
+    s: &str,
 ) -> Result<i128, ChineseToNumberError> {
-    let length = chars.len();
-
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
-    }
-
-    let c = chars[0];
+    chinese_to_signed_integer_compound(method, default_compound_unit(method), s)
+}
 
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
+pub(crate) fn chinese_to_signed_integer_compound(
+    method: ChineseCountMethod,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+) -> Result<i128, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
-    let uint = match chinese_to_unsigned_integer(method, &chars[offset..]) {
+    let uint = match chinese_to_unsigned_integer_compound(method, compound_unit, &s[offset..]) {
         Ok(n) => n,
         Err(error) => {
             return match error {
-                ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index,
-                } => Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index + offset,
-                }),
                 ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
                     Err(ChineseToNumberError::Underflow)
                 },
-                _ => Err(error),
+                _ => Err(error.relocate(s, offset)),
             };
         },
     };
@@ -373,6 +611,10 @@ fn get_exp_base_f64(method: ChineseCountMethod, exp: ChineseExponent) -> f64 {
                 1_0000_0000f64.powi((exp.ordinal() - ChineseExponent::萬.ordinal()) as i32)
             },
         },
+        ChineseCountMethod::Mainland => match exp {
+            ChineseExponent::兆 => 100_0000f64,
+            _ => get_exp_base_f64(ChineseCountMethod::TenThousand, exp),
+        },
         ChineseCountMethod::High => match exp {
             ChineseExponent::個 => 1f64,
             ChineseExponent::十 => 10f64,
@@ -393,78 +635,86 @@ fn get_exp_base_f64(method: ChineseCountMethod, exp: ChineseExponent) -> f64 {
     }
 }
 
-pub(crate) fn chinese_to_f64_unit(
+fn chinese_to_f64_unit(
     method: ChineseCountMethod,
-    chars: &[char],
-    mut pointer: usize,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+    end: usize,
     level: ChineseExponent,
 ) -> Result<(f64, Option<(usize, ChineseExponent)>), ChineseToNumberError> {
-    debug_assert!(!chars.is_empty() && pointer < chars.len());
+    let (mut pointer, c) = char_before(s, end).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
     let base = get_exp_base_f64(method, level);
 
-    let (n, exp) = match ChineseNumber::from_char(chars[pointer]) {
+    let (n, exp) = match ChineseNumber::from_char(c) {
         Some(n) if n == ChineseNumber::十 => {
-            if pointer == 0 {
+            if char_before(s, pointer).is_none() {
                 return Ok(((n.ordinal() as f64) * base, None));
             }
 
             (0f64, ChineseExponent::十)
         },
         Some(n) => {
-            if pointer == 0 {
-                return Ok(((n.ordinal() as f64) * base, None));
-            }
-
-            pointer -= 1;
+            let (mut pointer_before, mut c) = match char_before(s, pointer) {
+                Some(pair) => pair,
+                None => return Ok(((n.ordinal() as f64) * base, None)),
+            };
 
             loop {
-                match ChineseExponent::from_char(chars[pointer]) {
-                    Some(exp) if exp > ChineseExponent::個 => {
-                        if pointer == 0 {
+                match exponent_at(s, pointer_before) {
+                    Some((exp, start)) if exp > ChineseExponent::個 => {
+                        if char_before(s, start).is_none() {
                             if exp == ChineseExponent::十 {
                                 return Ok((((10 + n.ordinal()) as f64) * base, None));
                             } else {
-                                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                                    char_index: pointer,
-                                });
+                                return Err(ChineseToNumberError::incorrect(
+                                    s,
+                                    start,
+                                    ChineseNumberExpected::Digit,
+                                ));
                             }
                         }
 
+                        pointer = start;
+
                         break (n.ordinal() as f64, exp);
                     },
-                    _ => match ChineseNumber::from_char(chars[pointer]) {
+                    _ => match ChineseNumber::from_char(c) {
                         Some(ChineseNumber::零) => {
-                            if pointer == 0 {
-                                return Ok(((n.ordinal() as f64) * base, None));
+                            match char_before(s, pointer_before) {
+                                Some(pair) => (pointer_before, c) = pair,
+                                None => return Ok(((n.ordinal() as f64) * base, None)),
                             }
 
-                            pointer -= 1;
-
                             continue;
                         },
                         _ => {
-                            return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                                char_index: pointer,
-                            });
+                            return Err(unexpected(s, pointer_before, ChineseNumberExpected::Unit));
                         },
                     },
                 }
             }
         },
         _ => {
-            if pointer == 0 {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: pointer
-                });
+            if char_before(s, pointer).is_none() {
+                return Err(unexpected(s, pointer, ChineseNumberExpected::Digit));
             }
 
-            match ChineseExponent::from_char(chars[pointer]) {
-                Some(exp) if exp < level => (0f64, exp),
-                _ => {
-                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                        char_index: pointer,
-                    });
+            match exponent_at(s, pointer) {
+                Some((exp, start))
+                    if exp > ChineseExponent::個
+                        && compare_unit(method, compound_unit, exp, level).is_lt()
+                        && char_before(s, start).is_some() =>
+                {
+                    pointer = start;
+
+                    (0f64, exp)
+                },
+                Some((exp, start)) => {
+                    return Err(misplaced_unit(s, start, exp, level));
+                },
+                None => {
+                    return Err(unexpected(s, pointer, ChineseNumberExpected::Digit));
                 },
             }
         },
@@ -474,20 +724,20 @@ pub(crate) fn chinese_to_f64_unit(
     let mut next = Some((pointer, exp));
 
     while let Some((pointer, exp)) = next {
-        match exp.cmp(&level) {
+        match compare_unit(method, compound_unit, exp, level) {
             Ordering::Greater => {
                 break;
             },
             Ordering::Less => {
-                let result = chinese_to_f64_unit(method, chars, pointer - 1, exp)?;
+                let result = chinese_to_f64_unit(method, compound_unit, s, pointer, exp)?;
 
                 sum += result.0;
 
                 next = result.1;
             },
             Ordering::Equal => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: pointer
+                return Err(ChineseToNumberError::DuplicateUnit {
+                    position: ChineseNumberPosition::locate(s, pointer),
                 });
             },
         }
@@ -498,47 +748,41 @@ pub(crate) fn chinese_to_f64_unit(
     Ok((sum, next))
 }
 
-pub(crate) fn chinese_to_unsigned_f64(
+fn chinese_to_unsigned_f64(
     method: ChineseCountMethod,
-    chars: &[char],
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
 ) -> Result<f64, ChineseToNumberError> {
-    let length = chars.len();
-
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
-    }
+    let (last, _) = char_before(s, s.len()).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
-    let mut pointer = length - 1;
-
-    let mut exp = match ChineseExponent::from_char(chars[pointer]) {
-        Some(exp) if exp > ChineseExponent::個 => {
-            if pointer == 0 {
+    let (mut end, mut exp) = match exponent_at(s, last) {
+        Some((exp, start)) if exp > ChineseExponent::個 => {
+            if char_before(s, start).is_none() {
                 if exp == ChineseExponent::十 {
                     return Ok(10f64);
                 } else {
-                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                        char_index: pointer,
-                    });
+                    return Err(ChineseToNumberError::incorrect(
+                        s,
+                        start,
+                        ChineseNumberExpected::Digit,
+                    ));
                 }
             }
 
-            exp
-        },
-        _ => {
-            pointer += 1;
-            ChineseExponent::個
+            (start, exp)
         },
+        _ => (s.len(), ChineseExponent::個),
     };
 
     let mut sum = 0f64;
 
     loop {
-        let result = chinese_to_f64_unit(method, chars, pointer - 1, exp)?;
+        let result = chinese_to_f64_unit(method, compound_unit, s, end, exp)?;
 
         sum += result.0;
 
         if let Some((p, e)) = result.1 {
-            pointer = p;
+            end = p;
             exp = e;
 
             continue;
@@ -550,95 +794,175 @@ pub(crate) fn chinese_to_unsigned_f64(
     Ok(sum)
 }
 
-pub(crate) fn chinese_to_f64(
-    method: ChineseCountMethod,
-    chars: &[char],
-) -> Result<f64, ChineseToNumberError> {
-    let length = chars.len();
-
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
+pub(crate) fn split_money_fraction(
+    s: &str,
+    offset: usize,
+) -> Result<(u8, usize), ChineseToNumberError> {
+    // Returns the last character before `end` which is not the sign.
+    let char_before_number = |end| char_before(s, end).filter(|&(index, _)| index >= offset);
+
+    let mut fraction = 0u8;
+    let mut end = s.len();
+
+    for (unit, scale) in [(ChineseExponent::分, 1), (ChineseExponent::角, 10)] {
+        if let Some((unit_index, c)) = char_before_number(end)
+            && ChineseExponent::from_char(c) == Some(unit)
+        {
+            let (digit_index, c) = char_before_number(unit_index).ok_or_else(|| {
+                ChineseToNumberError::incorrect(s, unit_index, ChineseNumberExpected::Digit)
+            })?;
+
+            match ChineseNumber::from_char(c) {
+                Some(n) if n != ChineseNumber::十 => {
+                    fraction += n.ordinal() * scale;
+
+                    end = digit_index;
+                },
+                _ => {
+                    return Err(unexpected(s, digit_index, ChineseNumberExpected::Digit));
+                },
+            }
+        }
     }
 
-    let mut end = length - 1;
-    let mut fraction = 0.00;
+    Ok((fraction, end))
+}
 
-    if let Some(ChineseExponent::分) = ChineseExponent::from_char(chars[end]) {
-        if end == 0 {
-            return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                char_index: end
-            });
-        }
+#[inline]
+pub(crate) fn chinese_to_f64(
+    method: ChineseCountMethod,
+    s: &str,
+) -> Result<f64, ChineseToNumberError> {
+    chinese_to_f64_compound(method, default_compound_unit(method), s)
+}
 
-        end -= 1;
+pub(crate) fn chinese_to_f64_compound(
+    method: ChineseCountMethod,
+    compound_unit: Option<ChineseExponent>,
+    s: &str,
+) -> Result<f64, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
-        match ChineseNumber::from_char(chars[end]) {
-            Some(n) if n != ChineseNumber::十 => {
-                fraction += n.ordinal() as f64 * 0.01;
+    let (fraction, end) = split_money_fraction(s, offset)?;
 
-                if end == 0 {
-                    return Ok(fraction);
-                }
+    let fraction = fraction as f64 / 100f64;
 
-                end -= 1;
-            },
-            _ => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: end
-                });
+    let f = if end < s.len() && char_before(s, end).is_none_or(|(index, _)| index < offset) {
+        fraction
+    } else {
+        match chinese_to_unsigned_f64(method, compound_unit, &s[offset..end]) {
+            Ok(n) => n + fraction,
+            Err(error) => {
+                return match error {
+                    ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
+                        Err(ChineseToNumberError::Underflow)
+                    },
+                    _ => Err(error.relocate(s, offset)),
+                };
             },
         }
+    };
+
+    match sign {
+        ChineseSign::正 => Ok(f),
+        ChineseSign::負 => Ok(-f),
     }
+}
 
-    if let Some(ChineseExponent::角) = ChineseExponent::from_char(chars[end]) {
-        if end == 0 {
-            return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                char_index: end
-            });
+pub(crate) fn split_decimal_unit_fraction(
+    s: &str,
+    offset: usize,
+) -> Result<(u32, usize, Option<ChineseDecimalUnit>), ChineseToNumberError> {
+    // Returns the last character before `end` which is not the sign.
+    let char_before_number = |end| char_before(s, end).filter(|&(index, _)| index >= offset);
+
+    // The fraction is accumulated in 沙 as an integer so that "三分五釐" becomes exactly `35 / 100` after a single division.
+    let mut fraction = 0u32;
+    let mut smallest_unit = None;
+    let mut previous_unit: Option<ChineseDecimalUnit> = None;
+    let mut end = s.len();
+
+    while let Some((index, c)) = char_before_number(end) {
+        let unit = match ChineseDecimalUnit::from_char(c) {
+            Some(unit) => unit,
+            None => break,
+        };
+
+        // 沙 can also be the last character of the large unit 恆河沙.
+        if let Some((ChineseExponent::恆河沙, _)) = exponent_at(s, index) {
+            break;
         }
 
-        end -= 1;
+        if let Some(previous_unit) = previous_unit
+            && unit >= previous_unit
+        {
+            let position = ChineseNumberPosition::locate(s, index);
 
-        match ChineseNumber::from_char(chars[end]) {
-            Some(n) if n != ChineseNumber::十 => {
-                fraction += n.ordinal() as f64 * 0.1;
-
-                if end == 0 {
-                    return Ok(fraction);
+            return Err(if unit == previous_unit {
+                ChineseToNumberError::DuplicateUnit {
+                    position,
+                }
+            } else {
+                ChineseToNumberError::UnitOrder {
+                    position,
                 }
+            });
+        }
+
+        let (digit_index, c) = char_before_number(index).ok_or_else(|| {
+            ChineseToNumberError::incorrect(s, index, ChineseNumberExpected::Digit)
+        })?;
 
-                end -= 1;
+        match ChineseNumber::from_char(c) {
+            Some(n) if n != ChineseNumber::零 && n != ChineseNumber::十 => {
+                fraction += n.ordinal() as u32 * 10u32.pow(7 - unit.ordinal() as u32);
             },
             _ => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: end
-                });
+                return Err(unexpected(s, digit_index, ChineseNumberExpected::Digit));
             },
         }
-    }
 
-    let c = chars[0];
+        smallest_unit.get_or_insert(unit);
+        previous_unit = Some(unit);
+        end = digit_index;
 
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
+        // A single 零 stands for the skipped positions before this unit.
+        if let Some((zero_index, c)) = char_before_number(end)
+            && ChineseNumber::from_char(c) == Some(ChineseNumber::零)
+        {
+            end = zero_index;
+        }
+    }
 
-    let f = match chinese_to_unsigned_f64(method, &chars[offset..=end]) {
-        Ok(n) => n + fraction,
-        Err(error) => {
-            return match error {
-                ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index,
-                } => Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index + offset,
-                }),
-                ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
-                    Err(ChineseToNumberError::Underflow)
-                },
-                _ => Err(error),
-            };
-        },
+    Ok((fraction, end, smallest_unit))
+}
+
+pub(crate) fn chinese_to_f64_decimal_unit(
+    method: ChineseCountMethod,
+    s: &str,
+) -> Result<f64, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
+
+    let (fraction, end, smallest_unit) = split_decimal_unit_fraction(s, offset)?;
+
+    let fraction = fraction as f64 / 1_0000_0000f64;
+
+    let f = if smallest_unit.is_some()
+        && char_before(s, end).is_none_or(|(index, _)| index < offset)
+    {
+        fraction
+    } else {
+        match chinese_to_unsigned_f64(method, default_compound_unit(method), &s[offset..end]) {
+            Ok(n) => n + fraction,
+            Err(error) => {
+                return match error {
+                    ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
+                        Err(ChineseToNumberError::Underflow)
+                    },
+                    _ => Err(error.relocate(s, offset)),
+                };
+            },
+        }
     };
 
     match sign {
@@ -646,3 +970,8 @@ pub(crate) fn chinese_to_f64(
         ChineseSign::負 => Ok(-f),
     }
 }
+
+#[inline]
+pub(crate) const fn is_range_separator(c: char) -> bool {
+    matches!(c, '至' | '到' | '～' | '~' | '-' | '－')
+}
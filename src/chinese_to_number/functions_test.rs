@@ -1,6 +1,9 @@
 #![cfg(test)]
 
+use alloc::string::String;
+
 use super::*;
+use crate::{ChineseNumberExpected, ChineseNumberPosition};
 
 #[test]
 fn test_chinese_to_unsigned_integer_low() {
@@ -8,7 +11,7 @@ fn test_chinese_to_unsigned_integer_low() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::Low, &to_chars_vec($value))
+                chinese_to_unsigned_integer(ChineseCountMethod::Low, $value)
                     .unwrap()
             );
         };
@@ -54,7 +57,7 @@ fn test_chinese_to_unsigned_integer_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &to_chars_vec($value))
+                chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, $value)
                     .unwrap()
             );
         };
@@ -100,7 +103,7 @@ fn test_chinese_to_unsigned_integer_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::Middle, &to_chars_vec($value))
+                chinese_to_unsigned_integer(ChineseCountMethod::Middle, $value)
                     .unwrap()
             );
         };
@@ -149,7 +152,7 @@ fn test_chinese_to_unsigned_integer_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::High, &to_chars_vec($value))
+                chinese_to_unsigned_integer(ChineseCountMethod::High, $value)
                     .unwrap()
             );
         };
@@ -200,7 +203,7 @@ fn test_chinese_to_signed_integer_low() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_signed_integer(ChineseCountMethod::Low, &to_chars_vec($value)).unwrap()
+                chinese_to_signed_integer(ChineseCountMethod::Low, $value).unwrap()
             );
         };
     }
@@ -215,7 +218,7 @@ fn test_chinese_to_signed_integer_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_signed_integer(ChineseCountMethod::TenThousand, &to_chars_vec($value))
+                chinese_to_signed_integer(ChineseCountMethod::TenThousand, $value)
                     .unwrap()
             );
         };
@@ -230,7 +233,7 @@ fn test_chinese_to_signed_integer_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_signed_integer(ChineseCountMethod::Middle, &to_chars_vec($value))
+                chinese_to_signed_integer(ChineseCountMethod::Middle, $value)
                     .unwrap()
             );
         };
@@ -246,7 +249,7 @@ fn test_chinese_to_signed_integer_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_signed_integer(ChineseCountMethod::High, &to_chars_vec($value)).unwrap()
+                chinese_to_signed_integer(ChineseCountMethod::High, $value).unwrap()
             );
         };
     }
@@ -262,7 +265,7 @@ fn test_chinese_to_f64_low() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_f64(ChineseCountMethod::Low, &to_chars_vec($value)).unwrap()
+                chinese_to_f64(ChineseCountMethod::Low, $value).unwrap()
             );
         };
     }
@@ -311,7 +314,7 @@ fn test_chinese_to_f64_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_f64(ChineseCountMethod::TenThousand, &to_chars_vec($value)).unwrap()
+                chinese_to_f64(ChineseCountMethod::TenThousand, $value).unwrap()
             );
         };
     }
@@ -360,7 +363,7 @@ fn test_chinese_to_f64_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_f64(ChineseCountMethod::Middle, &to_chars_vec($value)).unwrap()
+                chinese_to_f64(ChineseCountMethod::Middle, $value).unwrap()
             );
         };
     }
@@ -408,7 +411,7 @@ fn test_chinese_to_f64_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_f64(ChineseCountMethod::High, &to_chars_vec($value)).unwrap()
+                chinese_to_f64(ChineseCountMethod::High, $value).unwrap()
             );
         };
     }
@@ -450,3 +453,218 @@ fn test_chinese_to_f64_high() {
     test!(1_0000_0000_0000_0000_0000_0000_0000f64, "一萬億兆");
     test!(1_0000_0000_0000_0000_0000_0000_0000_0000f64, "一京");
 }
+
+#[test]
+fn test_chinese_to_large_units() {
+    macro_rules! test {
+        ($expect:expr, $method:expr, $value:expr) => {
+            assert_eq!($expect, chinese_to_unsigned_integer($method, $value).unwrap());
+        };
+    }
+
+    test!(1_0000_0000_0000_0000, ChineseCountMethod::Low, "一恆河沙");
+    test!(1_0000_0000_0000_0000, ChineseCountMethod::Low, "一恒河沙");
+    test!(9_0000_0000_0000_0000_0001, ChineseCountMethod::Low, "九無量大數零一");
+    test!(9_0000_0000_0000_0000_0001, ChineseCountMethod::Low, "九无量大数零一");
+    test!(50_0000_0000_0000_0000_0000, ChineseCountMethod::Low, "五千那由他");
+    test!(2030_0000_0000_0000_0000, ChineseCountMethod::Low, "二不可思議三阿僧祇");
+    test!(1_2000_0000_0000_0000_0000, ChineseCountMethod::Low, "一無量大數二");
+
+    assert_eq!(
+        Err(ChineseToNumberError::Overflow),
+        chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, "一恆河沙")
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::MisplacedFraction {
+            position: ChineseNumberPosition {
+                char_index: 2,
+                byte_index: 6,
+                char: Some('沙'),
+            },
+        }),
+        chinese_to_unsigned_integer(ChineseCountMethod::Low, "一河沙")
+    );
+}
+
+#[test]
+fn test_chinese_to_f64_decimal_unit() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                chinese_to_f64_decimal_unit(ChineseCountMethod::TenThousand, $value)
+            );
+        };
+    }
+
+    test!(Ok(0.0), "零");
+    test!(Ok(0.35), "三分五厘");
+    test!(Ok(0.35), "三分五釐");
+    test!(Ok(0.002), "二毫");
+    test!(Ok(1.05), "一零五厘");
+    test!(Ok(100.05), "一百零五厘");
+    test!(Ok(0.103), "一分零三毫");
+    test!(Ok(-0.3), "負三分");
+    test!(Ok(0.000_000_12), "一纖二沙");
+    test!(Ok(0.000_000_12), "一纤二沙");
+    test!(Ok(1e52), "一恆河沙");
+    test!(Err(ChineseToNumberError::ChineseNumberEmpty), "");
+    test!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('分'),
+            },
+            expected: ChineseNumberExpected::Digit,
+        }),
+        "分"
+    );
+    test!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('十'),
+            },
+            expected: ChineseNumberExpected::Digit,
+        }),
+        "十分"
+    );
+    test!(
+        Err(ChineseToNumberError::UnitOrder {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('厘'),
+            },
+        }),
+        "五厘三分"
+    );
+}
+
+#[test]
+fn test_error_kind() {
+    macro_rules! test {
+        ($kind:ident, $byte_index:expr, $f:ident, $value:expr) => {
+            assert_eq!(
+                Err(ChineseToNumberError::$kind {
+                    position: ChineseNumberPosition::locate($value, $byte_index)
+                }),
+                $f(ChineseCountMethod::TenThousand, $value)
+            );
+        };
+        ($expected:ident, $byte_index:expr, $f:ident, $value:expr,) => {
+            assert_eq!(
+                Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    position: ChineseNumberPosition::locate($value, $byte_index),
+                    expected: ChineseNumberExpected::$expected,
+                }),
+                $f(ChineseCountMethod::TenThousand, $value)
+            );
+        };
+    }
+
+    test!(UnknownCharacter, 6, chinese_to_signed_integer, "一百X");
+    test!(DuplicateUnit, 3, chinese_to_signed_integer, "一萬萬");
+    test!(UnitOrder, 3, chinese_to_signed_integer, "五萬百");
+    test!(MisplacedSign, 6, chinese_to_signed_integer, "一萬負五");
+    test!(MisplacedFraction, 9, chinese_to_signed_integer, "一萬五角");
+    test!(Unit, 0, chinese_to_signed_integer, "五五",);
+    test!(Digit, 0, chinese_to_signed_integer, "百",);
+    test!(Digit, 3, chinese_to_signed_integer, "負萬",);
+    test!(MisplacedSign, 3, chinese_to_f64, "負負五角");
+    test!(DuplicateUnit, 3, chinese_to_f64_decimal_unit, "三分五分");
+    test!(MisplacedZero, 0, chinese_to_f64_decimal_unit, "零分");
+}
+
+#[test]
+fn test_whitespace() {
+    macro_rules! test {
+        ($f:ident, $value:expr) => {
+            let spaced = $value.chars().flat_map(|c| [' ', c]).chain([' ']).collect::<String>();
+
+            for method in ChineseCountMethod::variants() {
+                let result = $f(method, $value);
+                let spaced_result = $f(method, &spaced);
+
+                match (result.err().and_then(|error| error.position()), spaced_result.err()) {
+                    (Some(position), Some(spaced_error)) => {
+                        let spaced_position = spaced_error.position().unwrap();
+
+                        assert_eq!(
+                            result.unwrap_err().with_position(spaced_position),
+                            spaced_error
+                        );
+                        assert_eq!(position.char, spaced_position.char);
+                        assert_eq!(position.char_index * 2 + 1, spaced_position.char_index);
+                        assert_eq!(
+                            position.char.map(String::from).unwrap_or_default(),
+                            spaced[spaced_position.byte_range()]
+                        );
+                    },
+                    _ => assert_eq!(result, spaced_result),
+                }
+            }
+        };
+    }
+
+    test!(chinese_to_signed_integer, "");
+    test!(chinese_to_signed_integer, "一百二十三");
+    test!(chinese_to_signed_integer, "負一萬零五");
+    test!(chinese_to_signed_integer, "十五萬");
+    test!(chinese_to_signed_integer, "一萬二");
+    test!(chinese_to_signed_integer, "一恆河沙");
+    test!(chinese_to_signed_integer, "一百X");
+    test!(chinese_to_signed_integer, "一萬萬");
+    test!(chinese_to_signed_integer, "一萬負五");
+    test!(chinese_to_signed_integer, "一萬五角");
+    test!(chinese_to_f64, "負一萬零五");
+    test!(chinese_to_f64, "三角五分");
+    test!(chinese_to_f64, "三角X分");
+    test!(chinese_to_f64_decimal_unit, "三分五釐");
+    test!(chinese_to_f64_decimal_unit, "一恆河沙");
+    test!(chinese_to_f64_decimal_unit, "負五厘三分");
+}
+
+#[test]
+fn test_glyph_style() {
+    use crate::{
+        ChineseCase, ChineseExponent, ChineseVariant,
+        chinese_characters::{
+            ChineseDecimalUnit, ChineseGlyphStyle, ChineseNumber, ChinesePoint, ChineseSign,
+        },
+    };
+
+    for c in (0..=0x2FFFF).filter_map(char::from_u32) {
+        let accepted = ChineseNumber::from_char(c).is_some()
+            || ChineseExponent::from_char(c).is_some()
+            || ChineseDecimalUnit::from_char(c).is_some()
+            || ChineseSign::from_char(c).is_some()
+            || ChinesePoint::from_char(c).is_some();
+
+        if accepted {
+            assert!(ChineseGlyphStyle::from_char(c).is_some(), "{c}");
+        }
+    }
+
+    for (c, variant, case) in [
+        ('零', None, None),
+        ('二', None, Some(ChineseCase::Lower)),
+        ('貳', Some(ChineseVariant::Traditional), Some(ChineseCase::Upper)),
+        ('贰', Some(ChineseVariant::Simple), Some(ChineseCase::Upper)),
+        ('两', Some(ChineseVariant::Simple), Some(ChineseCase::Lower)),
+        ('萬', Some(ChineseVariant::Traditional), None),
+        ('恒', Some(ChineseVariant::Simple), None),
+    ] {
+        assert_eq!(
+            Some(ChineseGlyphStyle {
+                variant,
+                case,
+            }),
+            ChineseGlyphStyle::from_char(c)
+        );
+    }
+
+    assert_eq!(None, ChineseGlyphStyle::from_char('河'));
+}
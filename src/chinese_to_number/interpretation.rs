@@ -0,0 +1,106 @@
+use alloc::{vec, vec::Vec};
+
+use super::exponent_at;
+use crate::{
+    ChineseCountMethod, ChineseNumberPosition, ChineseToNumber, ChineseToNumberError,
+    chinese_characters::ChineseExponent,
+};
+
+/// 中文數字在一種或多種算術類型下解讀出的數值。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChineseInterpretation<T> {
+    /// 解讀出的數值。
+    pub value:   T,
+    /// 會解讀出此數值的算術類型。
+    pub methods: Vec<ChineseCountMethod>,
+}
+
+/// 中文數字在所有算術類型下的解讀。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChineseInterpretations<T> {
+    /// 不重複的解讀，依照 `ChineseCountMethod::variants()` 的順序排列。
+    pub interpretations: Vec<ChineseInterpretation<T>>,
+    /// 造成不同解讀的單位的位置，例如「一兆」中的「兆」。如果只有一種解讀，則為空。
+    pub ambiguous_units: Vec<ChineseNumberPosition>,
+}
+
+impl<T> ChineseInterpretations<T> {
+    /// 是否有多種解讀。
+    #[inline]
+    pub fn is_ambiguous(&self) -> bool {
+        self.interpretations.len() > 1
+    }
+}
+
+/// 在所有的算術類型下將中文數字轉成數值，並列出不重複的解讀。如果在所有的算術類型下都無法轉換，則回傳萬進的錯誤。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_to_interpretations, ChineseCountMethod};
+///
+/// let result = from_chinese_to_interpretations::<u64, _>("一兆").unwrap();
+///
+/// assert!(result.is_ambiguous());
+/// assert_eq!(1_000_000, result.interpretations[0].value);
+/// assert_eq!(
+///     vec![ChineseCountMethod::Low, ChineseCountMethod::Mainland],
+///     result.interpretations[0].methods
+/// );
+/// assert_eq!(1_000_000_000_000, result.interpretations[1].value);
+/// assert_eq!(10_000_000_000_000_000, result.interpretations[2].value);
+/// assert_eq!(
+///     vec![ChineseCountMethod::Middle, ChineseCountMethod::High],
+///     result.interpretations[2].methods
+/// );
+/// assert_eq!(Some('兆'), result.ambiguous_units[0].char);
+///
+/// assert!(!from_chinese_to_interpretations::<u64, _>("三萬").unwrap().is_ambiguous());
+/// ```
+pub fn from_chinese_to_interpretations<T: PartialEq, S: AsRef<str> + ChineseToNumber<T>>(
+    chinese_number: S,
+) -> Result<ChineseInterpretations<T>, ChineseToNumberError> {
+    let mut interpretations: Vec<ChineseInterpretation<T>> = Vec::new();
+    let mut error = None;
+
+    for method in ChineseCountMethod::variants() {
+        match chinese_number.to_number(method) {
+            Ok(value) => match interpretations.iter_mut().find(|i| i.value == value) {
+                Some(interpretation) => interpretation.methods.push(method),
+                None => interpretations.push(ChineseInterpretation {
+                    value,
+                    methods: vec![method],
+                }),
+            },
+            Err(e) if method == ChineseCountMethod::TenThousand => error = Some(e),
+            Err(_) => (),
+        }
+    }
+
+    if interpretations.is_empty() {
+        return Err(error.unwrap_or(ChineseToNumberError::ChineseNumberEmpty));
+    }
+
+    let ambiguous_units = if interpretations.len() > 1 {
+        method_dependent_units(chinese_number.as_ref())
+    } else {
+        Vec::new()
+    };
+
+    Ok(ChineseInterpretations {
+        interpretations,
+        ambiguous_units,
+    })
+}
+
+fn method_dependent_units(s: &str) -> Vec<ChineseNumberPosition> {
+    s.char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .filter_map(|(index, _)| match exponent_at(s, index) {
+            Some((exp, start)) if exp >= ChineseExponent::億 => {
+                Some(ChineseNumberPosition::locate(s, start))
+            },
+            _ => None,
+        })
+        .collect()
+}
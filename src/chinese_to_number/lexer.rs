@@ -0,0 +1,122 @@
+use core::ops::Range;
+
+use super::scanner::unit_at;
+use crate::{
+    ChineseExponent, ChineseNumberPosition, ChineseSign, ChineseToNumberError,
+    chinese_characters::{ChineseNumber, ChinesePoint},
+};
+
+/// 中文數字的字元種類。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseNumberTokenKind {
+    /// 一到九的數字，例如「一」、「貳」、「兩」、「3」。
+    Digit(u8),
+    /// 「零」、「〇」或「0」。
+    Zero,
+    /// 單位，例如「十」、「萬」、「個」、「恆河沙」。
+    Unit(ChineseExponent),
+    /// 正負號「正」或「負」。「正」只有在開頭時才會被當作正號，其它位置則會被當作單位。
+    Sign(ChineseSign),
+    /// 小數點「點」。
+    Point,
+}
+
+/// 中文數字中的一個字元或多字元的單位。
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseNumberToken {
+    /// 字元的種類。
+    pub kind:  ChineseNumberTokenKind,
+    /// 字元在字串中的位元組範圍。
+    pub range: Range<usize>,
+}
+
+/// 將字串拆成中文數字的字元。空白字元會被略過，無法辨識的字元則會產生錯誤，之後再繼續拆解。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseExponent, ChineseNumberLexer, ChineseNumberTokenKind, ChineseSign};
+///
+/// let kinds: Vec<_> = ChineseNumberLexer::new("負兩千〇五")
+///     .map(|token| token.unwrap().kind)
+///     .collect();
+///
+/// assert_eq!(
+///     vec![
+///         ChineseNumberTokenKind::Sign(ChineseSign::負),
+///         ChineseNumberTokenKind::Digit(2),
+///         ChineseNumberTokenKind::Unit(ChineseExponent::千),
+///         ChineseNumberTokenKind::Zero,
+///         ChineseNumberTokenKind::Digit(5),
+///     ],
+///     kinds
+/// );
+///
+/// let token = ChineseNumberLexer::new("一恆河沙").nth(1).unwrap().unwrap();
+///
+/// assert_eq!(ChineseNumberTokenKind::Unit(ChineseExponent::恆河沙), token.kind);
+/// assert_eq!(3..12, token.range);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChineseNumberLexer<'a> {
+    s:        &'a str,
+    position: usize,
+}
+
+impl<'a> ChineseNumberLexer<'a> {
+    /// 建立從 `s` 的開頭開始拆解的疊代器。
+    #[inline]
+    pub const fn new(s: &'a str) -> Self {
+        Self {
+            s,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for ChineseNumberLexer<'_> {
+    type Item = Result<ChineseNumberToken, ChineseToNumberError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.s;
+
+        let (start, c) = s[self.position..]
+            .char_indices()
+            .map(|(index, c)| (self.position + index, c))
+            .find(|(_, c)| !c.is_whitespace())?;
+
+        let mut end = start + c.len_utf8();
+
+        let kind = if let Some(number) = ChineseNumber::from_char(c)
+            && number != ChineseNumber::十
+        {
+            match number.ordinal() {
+                0 => ChineseNumberTokenKind::Zero,
+                n => ChineseNumberTokenKind::Digit(n),
+            }
+        } else if let Some(sign) = ChineseSign::from_char(c)
+            && (sign == ChineseSign::負 || s[..start].trim().is_empty())
+        {
+            ChineseNumberTokenKind::Sign(sign)
+        } else if let Some((exp, unit_end)) = unit_at(s, start) {
+            end = unit_end;
+
+            ChineseNumberTokenKind::Unit(exp)
+        } else if ChinesePoint::from_char(c).is_some() {
+            ChineseNumberTokenKind::Point
+        } else {
+            self.position = end;
+
+            return Some(Err(ChineseToNumberError::UnknownCharacter {
+                position: ChineseNumberPosition::locate(s, start),
+            }));
+        };
+
+        self.position = end;
+
+        Some(Ok(ChineseNumberToken {
+            kind,
+            range: start..end,
+        }))
+    }
+}
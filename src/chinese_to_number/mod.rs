@@ -1,23 +1,49 @@
+mod approximate;
+mod arabic_text;
+mod ast;
 mod chinese_to_number_error;
+mod detector;
 mod functions;
+mod interpretation;
+mod lexer;
 mod naive;
+#[cfg(feature = "number-to-chinese")]
+mod normalize;
+mod parse_options;
+mod scanner;
+mod style;
 mod traits;
+#[cfg(feature = "number-to-chinese")]
+mod transliterate;
 
 mod functions_test;
 
+pub use approximate::*;
+pub use arabic_text::*;
+pub use ast::*;
 pub use chinese_to_number_error::*;
+pub use detector::*;
 use functions::*;
+pub use interpretation::*;
+pub use lexer::*;
 pub use naive::*;
+#[cfg(feature = "number-to-chinese")]
+pub use normalize::*;
+pub use parse_options::*;
+pub use scanner::*;
+pub use style::*;
 pub use traits::*;
+#[cfg(feature = "number-to-chinese")]
+pub use transliterate::*;
 
-use crate::ChineseCountMethod;
+use num_traits::NumCast;
+
+use crate::{ChineseCountMethod, ChineseLargestUnit, ChineseRange, chinese_range::read_range_low};
 
 /// 將中文數字轉成 `u8` 整數。
 #[inline]
 pub fn from_chinese_to_u8<S: AsRef<str>>(chinese_number: S) -> Result<u8, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > u8::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -29,9 +55,7 @@ pub fn from_chinese_to_u8<S: AsRef<str>>(chinese_number: S) -> Result<u8, Chines
 /// 將中文數字轉成 `u16` 整數。
 #[inline]
 pub fn from_chinese_to_u16<S: AsRef<str>>(chinese_number: S) -> Result<u16, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > u16::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -45,9 +69,7 @@ pub fn from_chinese_to_u16<S: AsRef<str>>(chinese_number: S) -> Result<u16, Chin
 pub fn from_chinese_to_u32_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -61,9 +83,7 @@ pub fn from_chinese_to_u32_low<S: AsRef<str>>(
 pub fn from_chinese_to_u32_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -77,9 +97,7 @@ pub fn from_chinese_to_u32_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_u32_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -93,9 +111,21 @@ pub fn from_chinese_to_u32_middle<S: AsRef<str>>(
 pub fn from_chinese_to_u32_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    if n > u32::MAX as u128 {
+        return Err(ChineseToNumberError::Overflow);
+    }
+
+    Ok(n as u32)
+}
+
+/// 將中文數字轉成 `u32` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_u32_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<u32, ChineseToNumberError> {
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -109,9 +139,7 @@ pub fn from_chinese_to_u32_high<S: AsRef<str>>(
 pub fn from_chinese_to_u64_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -125,9 +153,7 @@ pub fn from_chinese_to_u64_low<S: AsRef<str>>(
 pub fn from_chinese_to_u64_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -141,9 +167,7 @@ pub fn from_chinese_to_u64_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_u64_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -157,9 +181,21 @@ pub fn from_chinese_to_u64_middle<S: AsRef<str>>(
 pub fn from_chinese_to_u64_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    if n > u64::MAX as u128 {
+        return Err(ChineseToNumberError::Overflow);
+    }
+
+    Ok(n as u64)
+}
+
+/// 將中文數字轉成 `u64` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_u64_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<u64, ChineseToNumberError> {
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -173,9 +209,7 @@ pub fn from_chinese_to_u64_high<S: AsRef<str>>(
 pub fn from_chinese_to_u128_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)
+    chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `u128` 整數。使用 **「萬進」**。
@@ -183,9 +217,7 @@ pub fn from_chinese_to_u128_low<S: AsRef<str>>(
 pub fn from_chinese_to_u128_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)
+    chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `u128` 整數。使用 **「中數」**。
@@ -193,9 +225,7 @@ pub fn from_chinese_to_u128_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_u128_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)
+    chinese_to_unsigned_integer(ChineseCountMethod::Middle, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `u128` 整數。使用 **「上數」**。
@@ -203,9 +233,15 @@ pub fn from_chinese_to_u128_middle<S: AsRef<str>>(
 pub fn from_chinese_to_u128_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    chinese_to_unsigned_integer(ChineseCountMethod::High, chinese_number.as_ref())
+}
 
-    chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)
+/// 將中文數字轉成 `u128` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_u128_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<u128, ChineseToNumberError> {
+    chinese_to_unsigned_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `usize` 整數。使用 **「下數」**。
@@ -213,9 +249,7 @@ pub fn from_chinese_to_u128_high<S: AsRef<str>>(
 pub fn from_chinese_to_usize_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<usize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -229,9 +263,7 @@ pub fn from_chinese_to_usize_low<S: AsRef<str>>(
 pub fn from_chinese_to_usize_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<usize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -245,9 +277,7 @@ pub fn from_chinese_to_usize_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_usize_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<usize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -261,9 +291,21 @@ pub fn from_chinese_to_usize_middle<S: AsRef<str>>(
 pub fn from_chinese_to_usize_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<usize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
+
+    if n > usize::MAX as u128 {
+        return Err(ChineseToNumberError::Overflow);
+    }
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    Ok(n as usize)
+}
+
+/// 將中文數字轉成 `usize` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_usize_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<usize, ChineseToNumberError> {
+    let n = chinese_to_unsigned_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -275,9 +317,7 @@ pub fn from_chinese_to_usize_high<S: AsRef<str>>(
 /// 將中文數字轉成 `i8` 整數。
 #[inline]
 pub fn from_chinese_to_i8<S: AsRef<str>>(chinese_number: S) -> Result<i8, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > i8::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -291,9 +331,7 @@ pub fn from_chinese_to_i8<S: AsRef<str>>(chinese_number: S) -> Result<i8, Chines
 /// 將中文數字轉成 `i16` 整數。
 #[inline]
 pub fn from_chinese_to_i16<S: AsRef<str>>(chinese_number: S) -> Result<i16, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > i16::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -309,9 +347,7 @@ pub fn from_chinese_to_i16<S: AsRef<str>>(chinese_number: S) -> Result<i16, Chin
 pub fn from_chinese_to_i32_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > i32::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -327,9 +363,7 @@ pub fn from_chinese_to_i32_low<S: AsRef<str>>(
 pub fn from_chinese_to_i32_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > i32::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -345,9 +379,7 @@ pub fn from_chinese_to_i32_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_i32_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > i32::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -363,9 +395,23 @@ pub fn from_chinese_to_i32_middle<S: AsRef<str>>(
 pub fn from_chinese_to_i32_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_signed_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
 
-    let n = chinese_to_signed_integer(ChineseCountMethod::High, &chars)?;
+    if n > i32::MAX as i128 {
+        return Err(ChineseToNumberError::Overflow);
+    } else if n < i32::MIN as i128 {
+        return Err(ChineseToNumberError::Underflow);
+    }
+
+    Ok(n as i32)
+}
+
+/// 將中文數字轉成 `i32` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_i32_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<i32, ChineseToNumberError> {
+    let n = chinese_to_signed_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > i32::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -381,9 +427,7 @@ pub fn from_chinese_to_i32_high<S: AsRef<str>>(
 pub fn from_chinese_to_i64_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > i64::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -399,9 +443,7 @@ pub fn from_chinese_to_i64_low<S: AsRef<str>>(
 pub fn from_chinese_to_i64_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > i64::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -417,9 +459,7 @@ pub fn from_chinese_to_i64_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_i64_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > i64::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -435,9 +475,23 @@ pub fn from_chinese_to_i64_middle<S: AsRef<str>>(
 pub fn from_chinese_to_i64_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_signed_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
 
-    let n = chinese_to_signed_integer(ChineseCountMethod::High, &chars)?;
+    if n > i64::MAX as i128 {
+        return Err(ChineseToNumberError::Overflow);
+    } else if n < i64::MIN as i128 {
+        return Err(ChineseToNumberError::Underflow);
+    }
+
+    Ok(n as i64)
+}
+
+/// 將中文數字轉成 `i64` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_i64_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<i64, ChineseToNumberError> {
+    let n = chinese_to_signed_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > i64::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -453,9 +507,7 @@ pub fn from_chinese_to_i64_high<S: AsRef<str>>(
 pub fn from_chinese_to_i128_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_signed_integer(ChineseCountMethod::Low, &chars)
+    chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `i128` 整數。使用 **「萬進」**。
@@ -463,9 +515,7 @@ pub fn from_chinese_to_i128_low<S: AsRef<str>>(
 pub fn from_chinese_to_i128_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_signed_integer(ChineseCountMethod::TenThousand, &chars)
+    chinese_to_signed_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `i128` 整數。使用 **「中數」**。
@@ -473,9 +523,7 @@ pub fn from_chinese_to_i128_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_i128_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_signed_integer(ChineseCountMethod::Middle, &chars)
+    chinese_to_signed_integer(ChineseCountMethod::Middle, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `i128` 整數。使用 **「上數」**。
@@ -483,9 +531,15 @@ pub fn from_chinese_to_i128_middle<S: AsRef<str>>(
 pub fn from_chinese_to_i128_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    chinese_to_signed_integer(ChineseCountMethod::High, chinese_number.as_ref())
+}
 
-    chinese_to_signed_integer(ChineseCountMethod::High, &chars)
+/// 將中文數字轉成 `i128` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_i128_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<i128, ChineseToNumberError> {
+    chinese_to_signed_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `isize` 整數。使用 **「下數」**。
@@ -493,9 +547,7 @@ pub fn from_chinese_to_i128_high<S: AsRef<str>>(
 pub fn from_chinese_to_isize_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<isize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Low, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Low, chinese_number.as_ref())?;
 
     if n > isize::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -511,9 +563,7 @@ pub fn from_chinese_to_isize_low<S: AsRef<str>>(
 pub fn from_chinese_to_isize_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<isize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::TenThousand, chinese_number.as_ref())?;
 
     if n > isize::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -529,9 +579,7 @@ pub fn from_chinese_to_isize_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_isize_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<isize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, &chars)?;
+    let n = chinese_to_signed_integer(ChineseCountMethod::Middle, chinese_number.as_ref())?;
 
     if n > isize::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -547,9 +595,23 @@ pub fn from_chinese_to_isize_middle<S: AsRef<str>>(
 pub fn from_chinese_to_isize_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<isize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    let n = chinese_to_signed_integer(ChineseCountMethod::High, chinese_number.as_ref())?;
 
-    let n = chinese_to_signed_integer(ChineseCountMethod::High, &chars)?;
+    if n > isize::MAX as i128 {
+        return Err(ChineseToNumberError::Overflow);
+    } else if n < isize::MIN as i128 {
+        return Err(ChineseToNumberError::Underflow);
+    }
+
+    Ok(n as isize)
+}
+
+/// 將中文數字轉成 `isize` 整數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_isize_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<isize, ChineseToNumberError> {
+    let n = chinese_to_signed_integer(ChineseCountMethod::Mainland, chinese_number.as_ref())?;
 
     if n > isize::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -565,9 +627,7 @@ pub fn from_chinese_to_isize_high<S: AsRef<str>>(
 pub fn from_chinese_to_f32_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::Low, &chars).map(|f| f as f32)
+    chinese_to_f64(ChineseCountMethod::Low, chinese_number.as_ref()).map(|f| f as f32)
 }
 
 /// 將中文數字轉成 `f32` 浮點數。使用 **「萬進」**。
@@ -575,9 +635,7 @@ pub fn from_chinese_to_f32_low<S: AsRef<str>>(
 pub fn from_chinese_to_f32_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::TenThousand, &chars).map(|f| f as f32)
+    chinese_to_f64(ChineseCountMethod::TenThousand, chinese_number.as_ref()).map(|f| f as f32)
 }
 
 /// 將中文數字轉成 `f32` 浮點數。使用 **「中數」**。
@@ -585,9 +643,7 @@ pub fn from_chinese_to_f32_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_f32_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::Middle, &chars).map(|f| f as f32)
+    chinese_to_f64(ChineseCountMethod::Middle, chinese_number.as_ref()).map(|f| f as f32)
 }
 
 /// 將中文數字轉成 `f32` 浮點數。使用 **「上數」**。
@@ -595,9 +651,15 @@ pub fn from_chinese_to_f32_middle<S: AsRef<str>>(
 pub fn from_chinese_to_f32_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    chinese_to_f64(ChineseCountMethod::High, chinese_number.as_ref()).map(|f| f as f32)
+}
 
-    chinese_to_f64(ChineseCountMethod::High, &chars).map(|f| f as f32)
+/// 將中文數字轉成 `f32` 浮點數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_f32_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<f32, ChineseToNumberError> {
+    chinese_to_f64(ChineseCountMethod::Mainland, chinese_number.as_ref()).map(|f| f as f32)
 }
 
 /// 將中文數字轉成 `f64` 浮點數。使用 **「下數」**。
@@ -605,9 +667,7 @@ pub fn from_chinese_to_f32_high<S: AsRef<str>>(
 pub fn from_chinese_to_f64_low<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::Low, &chars)
+    chinese_to_f64(ChineseCountMethod::Low, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `f64` 浮點數。使用 **「萬進」**。
@@ -615,9 +675,7 @@ pub fn from_chinese_to_f64_low<S: AsRef<str>>(
 pub fn from_chinese_to_f64_ten_thousand<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::TenThousand, &chars)
+    chinese_to_f64(ChineseCountMethod::TenThousand, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `f64` 浮點數。使用 **「中數」**。
@@ -625,9 +683,7 @@ pub fn from_chinese_to_f64_ten_thousand<S: AsRef<str>>(
 pub fn from_chinese_to_f64_middle<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(ChineseCountMethod::Middle, &chars)
+    chinese_to_f64(ChineseCountMethod::Middle, chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `f64` 浮點數。使用 **「上數」**。
@@ -635,7 +691,154 @@ pub fn from_chinese_to_f64_middle<S: AsRef<str>>(
 pub fn from_chinese_to_f64_high<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
+    chinese_to_f64(ChineseCountMethod::High, chinese_number.as_ref())
+}
+
+/// 將中文數字轉成 `f64` 浮點數。使用 **「大陸用法」**。
+#[inline]
+pub fn from_chinese_to_f64_mainland<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<f64, ChineseToNumberError> {
+    chinese_to_f64(ChineseCountMethod::Mainland, chinese_number.as_ref())
+}
+
+/// 將小數部份使用 **「分、釐、毫、絲、忽、微、纖、沙」** 等小數單位的中文數字轉成 `f32` 浮點數。
+#[inline]
+pub fn from_chinese_to_f32_decimal_unit<S: AsRef<str>>(
+    chinese_number: S,
+    method: ChineseCountMethod,
+) -> Result<f32, ChineseToNumberError> {
+    from_chinese_to_f64_decimal_unit(chinese_number, method).map(|f| f as f32)
+}
+
+/// 將小數部份使用 **「分、釐、毫、絲、忽、微、纖、沙」** 等小數單位的中文數字轉成 `f64` 浮點數。這裡的「分」為 `0.1`，而非金額的 `0.01`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_to_f64_decimal_unit, ChineseCountMethod};
+///
+/// assert_eq!(
+///     0.35,
+///     from_chinese_to_f64_decimal_unit("三分五厘", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_chinese_to_f64_decimal_unit<S: AsRef<str>>(
+    chinese_number: S,
+    method: ChineseCountMethod,
+) -> Result<f64, ChineseToNumberError> {
+    chinese_to_f64_decimal_unit(method, chinese_number.as_ref())
+}
+
+/// 將中文數字轉成 `u128` 整數。使用 **「萬進」**，但比 `largest_unit` 更大的數值以 `largest_unit` 組合，例如「一萬億」、「一億億」。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_to_u128_compound, ChineseLargestUnit};
+///
+/// assert_eq!(
+///     1_0000_0000_0000,
+///     from_chinese_to_u128_compound("一萬億", ChineseLargestUnit::億).unwrap()
+/// );
+/// assert_eq!(
+///     1_0000_0000_0000_0000,
+///     from_chinese_to_u128_compound("一億億", ChineseLargestUnit::億).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_chinese_to_u128_compound<S: AsRef<str>>(
+    chinese_number: S,
+    largest_unit: ChineseLargestUnit,
+) -> Result<u128, ChineseToNumberError> {
+    chinese_to_unsigned_integer_compound(
+        ChineseCountMethod::TenThousand,
+        Some(largest_unit.to_exponent()),
+        chinese_number.as_ref(),
+    )
+}
+
+/// 將中文數字轉成 `i128` 整數。使用 **「萬進」**，但比 `largest_unit` 更大的數值以 `largest_unit` 組合，例如「一萬億」、「一億億」。
+#[inline]
+pub fn from_chinese_to_i128_compound<S: AsRef<str>>(
+    chinese_number: S,
+    largest_unit: ChineseLargestUnit,
+) -> Result<i128, ChineseToNumberError> {
+    chinese_to_signed_integer_compound(
+        ChineseCountMethod::TenThousand,
+        Some(largest_unit.to_exponent()),
+        chinese_number.as_ref(),
+    )
+}
+
+/// 將中文數字轉成 `f64` 浮點數。使用 **「萬進」**，但比 `largest_unit` 更大的數值以 `largest_unit` 組合，例如「一萬億」、「一億億」。
+#[inline]
+pub fn from_chinese_to_f64_compound<S: AsRef<str>>(
+    chinese_number: S,
+    largest_unit: ChineseLargestUnit,
+) -> Result<f64, ChineseToNumberError> {
+    chinese_to_f64_compound(
+        ChineseCountMethod::TenThousand,
+        Some(largest_unit.to_exponent()),
+        chinese_number.as_ref(),
+    )
+}
+
+/// 將中文數字的範圍轉成數值範圍。上下限之間可以用「至」、「到」、「～」或「-」分隔。如果上限結尾的單位也適用於下限，例如「三至五萬」和「三至五千萬」，則下限也會套用該單位，但套用後的下限必須小於上限。
+///
+/// 上下限可以相反，例如「五至三」會轉成下限為 `5`、上限為 `3` 的範圍。此時因為套用單位後的下限不會小於上限，所以「五至三萬」的下限為 `5`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{from_chinese_to_range, ChineseCountMethod, ChineseRange};
+///
+/// assert_eq!(
+///     ChineseRange::new(30000u32, 50000),
+///     from_chinese_to_range("三至五萬", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     ChineseRange::new(10u32, 20),
+///     from_chinese_to_range("十到二十", ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// ```
+pub fn from_chinese_to_range<T: NumCast + PartialOrd + Copy, S: AsRef<str>>(
+    chinese_range: S,
+    method: ChineseCountMethod,
+) -> Result<ChineseRange<T>, ChineseToNumberError>
+where
+    for<'a> &'a str: ChineseToNumber<T>, {
+    let s = chinese_range.as_ref();
+
+    if s.trim().is_empty() {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let (separator_index, separator) = s
+        .char_indices()
+        .find(|(_, c)| is_range_separator(*c))
+        .ok_or_else(|| {
+            ChineseToNumberError::incorrect(s, s.len(), ChineseNumberExpected::RangeSeparator)
+        })?;
+
+    let high_index = separator_index + separator.len_utf8();
+
+    let low_str = &s[..separator_index];
+    let high_str = &s[high_index..];
+
+    if low_str.trim().is_empty() || high_str.trim().is_empty() {
+        return Err(ChineseToNumberError::incorrect(
+            s,
+            separator_index,
+            ChineseNumberExpected::Digit,
+        ));
+    }
+
+    let high: T = high_str.to_number(method).map_err(|error| error.relocate(s, high_index))?;
+
+    let low: T = low_str.to_number(method)?;
 
-    chinese_to_f64(ChineseCountMethod::High, &chars)
+    // "三至五萬" means 30000 to 50000, but "二至一百" means 2 to 100.
+    Ok(ChineseRange::new(read_range_low(method, low, high), high))
 }
@@ -2,28 +2,31 @@
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
 
-use super::to_chars_vec;
+use super::{split_sign, unexpected};
 use crate::{
-    ChineseToNumberError,
+    ChineseNumberExpected, ChineseToNumberError,
     chinese_characters::{ChineseNumber, ChinesePoint, ChineseSign},
 };
 
-fn chinese_to_unsigned_integer(chars: &[char]) -> Result<u128, ChineseToNumberError> {
-    let length = chars.len();
+#[inline]
+fn non_whitespace_chars(s: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    s.char_indices().filter(|(_, c)| !c.is_whitespace())
+}
 
-    if length == 0 {
+fn chinese_to_unsigned_integer(s: &str) -> Result<u128, ChineseToNumberError> {
+    let mut chars = non_whitespace_chars(s).peekable();
+
+    if chars.peek().is_none() {
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
     let mut sum = 0u128;
 
-    for (char_index, &char) in chars.iter().enumerate() {
+    for (index, char) in chars {
         let d = match ChineseNumber::from_char(char) {
             Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as u128,
             _ => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index,
-                });
+                return Err(unexpected(s, index, ChineseNumberExpected::Digit));
             },
         };
 
@@ -35,33 +38,17 @@ fn chinese_to_unsigned_integer(chars: &[char]) -> Result<u128, ChineseToNumberEr
     Ok(sum)
 }
 
-fn chinese_to_signed_integer(chars: &[char]) -> Result<i128, ChineseToNumberError> {
-    let length = chars.len();
-
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
-    }
-
-    let c = chars[0];
+fn chinese_to_signed_integer(s: &str) -> Result<i128, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
-
-    let uint = match chinese_to_unsigned_integer(&chars[offset..]) {
+    let uint = match chinese_to_unsigned_integer(&s[offset..]) {
         Ok(n) => n,
         Err(error) => {
             return match error {
-                ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index,
-                } => Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index + offset,
-                }),
                 ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
                     Err(ChineseToNumberError::Underflow)
                 },
-                _ => Err(error),
+                _ => Err(error.relocate(s, offset)),
             };
         },
     };
@@ -91,9 +78,7 @@ fn chinese_to_signed_integer(chars: &[char]) -> Result<i128, ChineseToNumberErro
 pub fn from_chinese_to_u8_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u8, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n = chinese_to_unsigned_integer(chinese_number.as_ref())?;
 
     if n > u8::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -107,9 +92,7 @@ pub fn from_chinese_to_u8_naive<S: AsRef<str>>(
 pub fn from_chinese_to_u16_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u16, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n = chinese_to_unsigned_integer(chinese_number.as_ref())?;
 
     if n > u16::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -123,9 +106,7 @@ pub fn from_chinese_to_u16_naive<S: AsRef<str>>(
 pub fn from_chinese_to_u32_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n = chinese_to_unsigned_integer(chinese_number.as_ref())?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -139,9 +120,7 @@ pub fn from_chinese_to_u32_naive<S: AsRef<str>>(
 pub fn from_chinese_to_u64_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n = chinese_to_unsigned_integer(chinese_number.as_ref())?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -155,9 +134,7 @@ pub fn from_chinese_to_u64_naive<S: AsRef<str>>(
 pub fn from_chinese_to_u128_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<u128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_unsigned_integer(&chars)
+    chinese_to_unsigned_integer(chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `usize` 整數。不進行單位計算。
@@ -165,9 +142,7 @@ pub fn from_chinese_to_u128_naive<S: AsRef<str>>(
 pub fn from_chinese_to_usize_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<usize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n = chinese_to_unsigned_integer(chinese_number.as_ref())?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -181,9 +156,7 @@ pub fn from_chinese_to_usize_naive<S: AsRef<str>>(
 pub fn from_chinese_to_i8_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i8, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(&chars)?;
+    let n = chinese_to_signed_integer(chinese_number.as_ref())?;
 
     if n > i8::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -199,9 +172,7 @@ pub fn from_chinese_to_i8_naive<S: AsRef<str>>(
 pub fn from_chinese_to_i16_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i16, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(&chars)?;
+    let n = chinese_to_signed_integer(chinese_number.as_ref())?;
 
     if n > i16::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -217,9 +188,7 @@ pub fn from_chinese_to_i16_naive<S: AsRef<str>>(
 pub fn from_chinese_to_i32_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(&chars)?;
+    let n = chinese_to_signed_integer(chinese_number.as_ref())?;
 
     if n > i32::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -235,9 +204,7 @@ pub fn from_chinese_to_i32_naive<S: AsRef<str>>(
 pub fn from_chinese_to_i64_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(&chars)?;
+    let n = chinese_to_signed_integer(chinese_number.as_ref())?;
 
     if n > i64::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -253,9 +220,7 @@ pub fn from_chinese_to_i64_naive<S: AsRef<str>>(
 pub fn from_chinese_to_i128_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<i128, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_signed_integer(&chars)
+    chinese_to_signed_integer(chinese_number.as_ref())
 }
 
 /// 將中文數字轉成 `isize` 整數。不進行單位計算。
@@ -263,9 +228,7 @@ pub fn from_chinese_to_i128_naive<S: AsRef<str>>(
 pub fn from_chinese_to_isize_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<isize, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    let n = chinese_to_signed_integer(&chars)?;
+    let n = chinese_to_signed_integer(chinese_number.as_ref())?;
 
     if n > isize::MAX as i128 {
         return Err(ChineseToNumberError::Overflow);
@@ -278,31 +241,20 @@ pub fn from_chinese_to_isize_naive<S: AsRef<str>>(
 
 // TODO f64
 
-fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
-    let length = chars.len();
-
-    if length == 0 {
-        return Err(ChineseToNumberError::ChineseNumberEmpty);
-    }
-
-    let (sign, offset) = match ChineseSign::from_char(chars[0]) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
+fn chinese_to_f64(s: &str) -> Result<f64, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
 
     let mut sum = 0f64;
 
-    let mut iter = chars[offset..].iter().enumerate();
+    let mut iter = non_whitespace_chars(s).skip_while(|&(i, _)| i < offset);
 
-    for (i, &char) in iter.by_ref() {
+    for (i, char) in iter.by_ref() {
         let d = match ChineseNumber::from_char(char) {
             Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as f64,
             _ => match ChinesePoint::from_char(char) {
                 Some(_) => break,
                 None => {
-                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                        char_index: i + offset,
-                    });
+                    return Err(unexpected(s, i, ChineseNumberExpected::Digit));
                 },
             },
         };
@@ -312,13 +264,11 @@ fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
         sum += d;
     }
 
-    for (c, (i, &char)) in (1i32..).zip(iter) {
+    for (c, (i, char)) in (1i32..).zip(iter) {
         let d = match ChineseNumber::from_char(char) {
             Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as f64,
             _ => {
-                return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: i + offset,
-                });
+                return Err(unexpected(s, i, ChineseNumberExpected::Digit));
             },
         };
 
@@ -336,9 +286,7 @@ fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
 pub fn from_chinese_to_f32_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f32, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(&chars).map(|f| f as f32)
+    chinese_to_f64(chinese_number.as_ref()).map(|f| f as f32)
 }
 
 /// 將中文數字轉成 `f64` 浮點數。不進行單位計算。
@@ -346,7 +294,5 @@ pub fn from_chinese_to_f32_naive<S: AsRef<str>>(
 pub fn from_chinese_to_f64_naive<S: AsRef<str>>(
     chinese_number: S,
 ) -> Result<f64, ChineseToNumberError> {
-    let chars = to_chars_vec(chinese_number.as_ref());
-
-    chinese_to_f64(&chars)
+    chinese_to_f64(chinese_number.as_ref())
 }
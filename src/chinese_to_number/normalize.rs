@@ -0,0 +1,316 @@
+use alloc::string::String;
+use core::iter;
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use super::{
+    char_before, chinese_to_big_unsigned_integer, detect_number_style,
+    parse_options::{abbreviation_at, case_of_char},
+    split_decimal_unit_fraction, split_money_fraction, split_sign,
+};
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseDetected, ChineseParseOptions, ChineseToNumberError,
+    ChineseVariant, big_integer_to_chinese_by_method,
+    chinese_characters::{ChineseDecimalUnit, ChineseExponent, ChineseNumber, ChineseSign},
+    prepend_negative_sign, push_decimal_unit_fraction, push_money_fraction,
+};
+
+/// 正規化後的中文數字。
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseNormalized {
+    /// 以 `to_chinese` 的寫法重新寫出的中文數字。
+    pub chinese:       String,
+    /// 重新寫出的中文數字在其它的算術類型下，是否會被解讀成和原本的寫法不同的數值。例如萬進的「一萬億」會被正規化成「一兆」，但在下數中兩者的數值不同。
+    pub reinterpreted: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct ChineseDecimal {
+    negative:     bool,
+    integer:      BigUint,
+    fraction:     u32,
+    decimal_unit: bool,
+}
+
+fn parse(method: ChineseCountMethod, s: &str) -> Result<ChineseDecimal, ChineseToNumberError> {
+    let (sign, offset) = split_sign(s).ok_or(ChineseToNumberError::ChineseNumberEmpty)?;
+
+    let (fraction, end, decimal_unit) = match split_decimal_unit_fraction(s, offset) {
+        Ok((fraction, end, Some(unit))) if unit != ChineseDecimalUnit::分 => (fraction, end, true),
+        _ => {
+            let (fraction, end) = split_money_fraction(s, offset)?;
+
+            (fraction as u32 * 100_0000, end, false)
+        },
+    };
+
+    let integer = if end < s.len() && char_before(s, end).is_none_or(|(index, _)| index < offset) {
+        BigUint::zero()
+    } else {
+        parse_integer(method, &s[offset..end]).map_err(|error| error.relocate(s, offset))?
+    };
+
+    Ok(ChineseDecimal {
+        negative: sign == ChineseSign::負 && !(integer.is_zero() && fraction == 0),
+        integer,
+        fraction,
+        decimal_unit,
+    })
+}
+
+fn parse_integer(method: ChineseCountMethod, s: &str) -> Result<BigUint, ChineseToNumberError> {
+    chinese_to_big_unsigned_integer(method, s).or_else(|error| {
+        match s.trim_start().chars().next().and_then(ChineseExponent::from_char) {
+            Some(exp) if exp >= ChineseExponent::百 => {
+                let mut prefixed = String::with_capacity(s.len() + 3);
+
+                prefixed.push('一');
+                prefixed.push_str(s);
+
+                chinese_to_big_unsigned_integer(method, &prefixed).map_err(|_| error)
+            },
+            _ => Err(error),
+        }
+    })
+}
+
+fn render(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: &ChineseDecimal,
+) -> Result<String, ChineseToNumberError> {
+    let mut s = if value.integer.is_zero() {
+        String::new()
+    } else {
+        big_integer_to_chinese_by_method(chinese_variant, chinese_case, method, &value.integer)
+            .map_err(|_| {
+                if value.negative {
+                    ChineseToNumberError::Underflow
+                } else {
+                    ChineseToNumberError::Overflow
+                }
+            })?
+    };
+
+    if value.decimal_unit {
+        push_decimal_unit_fraction(chinese_variant, chinese_case, &mut s, value.fraction);
+    } else {
+        let fraction = (value.fraction / 100_0000) as u8;
+
+        push_money_fraction(chinese_variant, chinese_case, &mut s, fraction);
+    }
+
+    if s.is_empty() {
+        s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+    }
+
+    if value.negative {
+        prepend_negative_sign(chinese_variant, &mut s);
+    }
+
+    Ok(s)
+}
+
+fn is_allowed_writing(c: char, rendered: char) -> bool {
+    let same = ChineseNumber::from_char(c) == ChineseNumber::from_char(rendered)
+        && ChineseExponent::from_char(c) == ChineseExponent::from_char(rendered);
+
+    same && (matches!(c, '兩' | '两')
+        || c.is_ascii_digit()
+        || case_of_char(c).is_some_and(|case| Some(case) != case_of_char(rendered)))
+}
+
+fn mismatch_at(s: &str, rendered: &str, options: ChineseParseOptions) -> Option<usize> {
+    let mut chars = s.char_indices().filter(|(_, c)| !c.is_whitespace());
+    let mut rendered_chars = rendered.chars();
+
+    loop {
+        match (chars.next(), rendered_chars.next()) {
+            (None, None) => return None,
+            (Some((index, c)), Some(rendered)) => {
+                if c != rendered && !is_allowed_writing(c, rendered) {
+                    return Some(index);
+                }
+            },
+            (None, Some(rendered)) => {
+                // An abbreviation omits the units after the last digit, such as 十 in "一百二" or 千萬 in "一億五".
+                let abbreviated = options.allow_abbreviation
+                    && abbreviation_at(s).is_some()
+                    && iter::once(rendered).chain(rendered_chars).all(|c| {
+                        ChineseNumber::from_char(c).is_none_or(|n| n == ChineseNumber::十)
+                    });
+
+                return if abbreviated { None } else { Some(s.len()) };
+            },
+            (Some((index, _)), None) => return Some(index),
+        }
+    }
+}
+
+pub(crate) fn non_canonical_at(
+    s: &str,
+    method: ChineseCountMethod,
+    options: ChineseParseOptions,
+) -> Option<usize> {
+    let Ok(value) = parse(method, s) else {
+        return Some(0);
+    };
+
+    let mut furthest = 0;
+
+    for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+        for chinese_case in [ChineseCase::Upper, ChineseCase::Lower] {
+            if let Ok(rendered) = render(chinese_variant, chinese_case, method, &value) {
+                furthest = furthest.max(mismatch_at(s, &rendered, options)?);
+            }
+        }
+    }
+
+    Some(furthest)
+}
+
+/// 判斷中文數字是否和 `to_chinese` 的寫法相同。`options` 允許的寫法不會被視為不同，例如允許空白字元時，「一百 二十」也會被當作是標準的寫法。只要在任一種算術類型下相同即可。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{is_canonical, ChineseParseOptions};
+///
+/// assert!(is_canonical("壹佰零貳", ChineseParseOptions::strict()));
+/// assert!(is_canonical("一萬億", ChineseParseOptions::strict()));
+/// assert!(!is_canonical("一百〇二", ChineseParseOptions::strict()));
+/// assert!(!is_canonical("百二", ChineseParseOptions::strict()));
+/// assert!(!is_canonical("一百二", ChineseParseOptions::strict()));
+/// assert!(is_canonical("一百二", ChineseParseOptions::lenient()));
+/// ```
+pub fn is_canonical<S: AsRef<str>>(chinese_number: S, options: ChineseParseOptions) -> bool {
+    let s = chinese_number.as_ref();
+
+    if options.check(s).is_err() {
+        return false;
+    }
+
+    ChineseCountMethod::variants().into_iter().any(|method| {
+        let value = match parse(method, s) {
+            Ok(value) => value,
+            Err(_) => return false,
+        };
+
+        [ChineseVariant::Traditional, ChineseVariant::Simple].into_iter().any(|chinese_variant| {
+            [ChineseCase::Upper, ChineseCase::Lower].into_iter().any(|chinese_case| {
+                render(chinese_variant, chinese_case, method, &value)
+                    .is_ok_and(|rendered| mismatch_at(s, &rendered, options).is_none())
+            })
+        })
+    })
+}
+
+/// 解析中文數字後，再以 `to_chinese` 的寫法重新寫出。數值可以超過 `u128`，小數部份也不會被捨入。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{normalize, ChineseCase, ChineseCountMethod, ChineseVariant};
+///
+/// for s in ["壹佰零貳", "一百〇二", "一百 零 2"] {
+///     let normalized = normalize(
+///         s,
+///         ChineseVariant::Traditional,
+///         ChineseCase::Upper,
+///         ChineseCountMethod::TenThousand,
+///     )
+///     .unwrap();
+///
+///     assert_eq!("壹佰零貳", normalized.chinese);
+/// }
+///
+/// let normalized = normalize(
+///     "百二",
+///     ChineseVariant::Simple,
+///     ChineseCase::Upper,
+///     ChineseCountMethod::TenThousand,
+/// )
+/// .unwrap();
+///
+/// assert_eq!("壹佰贰拾", normalized.chinese);
+///
+/// let normalized = normalize(
+///     "一萬億",
+///     ChineseVariant::Traditional,
+///     ChineseCase::Lower,
+///     ChineseCountMethod::TenThousand,
+/// )
+/// .unwrap();
+///
+/// assert_eq!("一兆", normalized.chinese);
+/// assert!(normalized.reinterpreted);
+/// ```
+pub fn normalize<S: AsRef<str>>(
+    chinese_number: S,
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+) -> Result<ChineseNormalized, ChineseToNumberError> {
+    let s = chinese_number.as_ref();
+
+    let chinese = render(chinese_variant, chinese_case, method, &parse(method, s)?)?;
+
+    let reinterpreted = ChineseCountMethod::variants()
+        .into_iter()
+        .any(|other| other != method && parse(other, s).ok() != parse(other, &chinese).ok());
+
+    Ok(ChineseNormalized {
+        chinese,
+        reinterpreted,
+    })
+}
+
+/// 將中文數字從一種算術類型的寫法換成另一種算術類型的寫法，例如下數的「一兆」（一百萬）在萬進中會寫成「一百萬」。數值可以超過 `u128`，字體和大小寫會沿用原本的寫法，無法判斷時則使用繁體和小寫。
+///
+/// 若數值太大而無法以 `to` 寫出，會回傳 `ChineseToNumberError::Overflow`，負數則回傳 `ChineseToNumberError::Underflow`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{convert_method, ChineseCountMethod, ChineseToNumberError};
+///
+/// assert_eq!(
+///     "一百萬",
+///     convert_method("一兆", ChineseCountMethod::Low, ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     "一澗",
+///     convert_method("一兆", ChineseCountMethod::TenThousand, ChineseCountMethod::Low).unwrap()
+/// );
+/// assert_eq!(
+///     "壹兆",
+///     convert_method("壹京", ChineseCountMethod::TenThousand, ChineseCountMethod::High).unwrap()
+/// );
+/// assert_eq!(
+///     Err(ChineseToNumberError::Overflow),
+///     convert_method("一無量大數", ChineseCountMethod::TenThousand, ChineseCountMethod::Low)
+/// );
+/// ```
+pub fn convert_method<S: AsRef<str>>(
+    chinese_number: S,
+    from: ChineseCountMethod,
+    to: ChineseCountMethod,
+) -> Result<String, ChineseToNumberError> {
+    let s = chinese_number.as_ref();
+
+    let style = detect_number_style(s, from);
+
+    let chinese_variant = match style.chinese_variant {
+        ChineseDetected::Uniform(chinese_variant) => chinese_variant,
+        _ => ChineseVariant::Traditional,
+    };
+
+    let chinese_case = match style.chinese_case {
+        ChineseDetected::Uniform(chinese_case) => chinese_case,
+        _ => ChineseCase::Lower,
+    };
+
+    render(chinese_variant, chinese_case, to, &parse(from, s)?)
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "number-to-chinese")]
+use super::normalize::non_canonical_at;
+use super::{char_before, exponent_at};
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseNumberPosition, ChineseToNumberError,
+    chinese_characters::{ChineseExponent, ChineseGlyphStyle, ChineseNumber},
+};
+
+/// 解析中文數字時可以接受的寫法。
+///
+/// 預設接受所有的寫法。使用 `strict` 則只接受 `to_chinese` 產生的寫法，可再個別開啟需要的寫法。檢查寫法是否和 `to_chinese` 相同需要啟用 `number-to-chinese` 特性，未啟用時不允許 `allow_non_canonical` 的解析會回傳 `ChineseToNumberError::CanonicalCheckUnavailable`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseCountMethod, ChineseParseOptions, ChineseToNumber};
+///
+/// let options = ChineseParseOptions {
+///     allow_whitespace: true,
+///     ..ChineseParseOptions::strict()
+/// };
+///
+/// # #[cfg(feature = "number-to-chinese")]
+/// assert_eq!(
+///     120u32,
+///     "一百 二十".to_number_with_options(ChineseCountMethod::TenThousand, options).unwrap()
+/// );
+/// assert!(
+///     ChineseToNumber::<u32>::to_number_with_options(
+///         &"一百二",
+///         ChineseCountMethod::TenThousand,
+///         options
+///     )
+///     .is_err()
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseParseOptions {
+    /// 是否接受省略最後一個單位的寫法，例如「一百二」或「三萬四」。
+    pub allow_abbreviation:  bool,
+    /// 是否接受以「兩」代替「二」。
+    pub allow_liang:         bool,
+    /// 是否接受阿拉伯數字，例如「3萬」。
+    pub allow_arabic_digits: bool,
+    /// 是否接受空白字元。
+    pub allow_whitespace:    bool,
+    /// 是否接受混用大寫和小寫數字，例如「壹百二十」。
+    pub allow_mixed_case:    bool,
+    /// 是否接受 `to_chinese` 不會產生的其它寫法，例如「一十二」、「一百〇二」或「零一」。
+    pub allow_non_canonical: bool,
+}
+
+impl ChineseParseOptions {
+    /// 接受所有的寫法。
+    #[inline]
+    pub const fn lenient() -> Self {
+        Self {
+            allow_abbreviation:  true,
+            allow_liang:         true,
+            allow_arabic_digits: true,
+            allow_whitespace:    true,
+            allow_mixed_case:    true,
+            allow_non_canonical: true,
+        }
+    }
+
+    /// 只接受 `to_chinese` 產生的寫法。
+    #[inline]
+    pub const fn strict() -> Self {
+        Self {
+            allow_abbreviation:  false,
+            allow_liang:         false,
+            allow_arabic_digits: false,
+            allow_whitespace:    false,
+            allow_mixed_case:    false,
+            allow_non_canonical: false,
+        }
+    }
+
+    /// 檢查中文數字是否只使用了允許的寫法。這個方法不會解析數值，所以不會檢查 `allow_non_canonical`。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseParseOptions, ChineseToNumberError};
+    ///
+    /// let options = ChineseParseOptions::strict();
+    ///
+    /// assert_eq!(Ok(()), options.check("壹佰零貳"));
+    ///
+    /// let error = options.check("兩千").unwrap_err();
+    ///
+    /// assert!(matches!(error, ChineseToNumberError::NotAllowed { .. }));
+    /// assert_eq!(0, error.position().unwrap().char_index);
+    /// ```
+    pub fn check<S: AsRef<str>>(&self, chinese_number: S) -> Result<(), ChineseToNumberError> {
+        let s = chinese_number.as_ref();
+
+        let mut case = None;
+
+        for (index, c) in s.char_indices() {
+            let allowed = if c.is_whitespace() {
+                self.allow_whitespace
+            } else if c.is_ascii_digit() {
+                self.allow_arabic_digits
+            } else if matches!(c, '兩' | '两') {
+                self.allow_liang
+            } else if let Some(c_case) = case_of_char(c)
+                && !self.allow_mixed_case
+            {
+                *case.get_or_insert(c_case) == c_case
+            } else {
+                true
+            };
+
+            if !allowed {
+                return Err(not_allowed(s, index));
+            }
+        }
+
+        if !self.allow_abbreviation
+            && let Some(index) = abbreviation_at(s)
+        {
+            return Err(not_allowed(s, index));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_canonical(
+        &self,
+        s: &str,
+        method: ChineseCountMethod,
+    ) -> Result<(), ChineseToNumberError> {
+        if self.allow_non_canonical {
+            return Ok(());
+        }
+
+        #[cfg(feature = "number-to-chinese")]
+        match non_canonical_at(s, method, *self) {
+            Some(index) => Err(not_allowed(s, index)),
+            None => Ok(()),
+        }
+
+        #[cfg(not(feature = "number-to-chinese"))]
+        {
+            let _ = (s, method);
+
+            Err(ChineseToNumberError::CanonicalCheckUnavailable)
+        }
+    }
+}
+
+impl Default for ChineseParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::lenient()
+    }
+}
+
+#[inline]
+fn not_allowed(s: &str, index: usize) -> ChineseToNumberError {
+    ChineseToNumberError::NotAllowed {
+        position: ChineseNumberPosition::locate(s, index),
+    }
+}
+
+#[inline]
+pub(crate) const fn case_of_char(c: char) -> Option<ChineseCase> {
+    match ChineseGlyphStyle::from_char(c) {
+        Some(style) => style.case,
+        None => None,
+    }
+}
+
+pub(crate) fn abbreviation_at(s: &str) -> Option<usize> {
+    let (last, c) = char_before(s, s.len())?;
+
+    match ChineseNumber::from_char(c) {
+        Some(ChineseNumber::零 | ChineseNumber::十) | None => return None,
+        Some(_) => (),
+    }
+
+    let (left, _) = char_before(s, last)?;
+
+    match exponent_at(s, left) {
+        Some((exp, start)) if exp >= ChineseExponent::百 && char_before(s, start).is_some() => {
+            Some(last)
+        },
+        _ => None,
+    }
+}
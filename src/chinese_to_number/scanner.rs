@@ -0,0 +1,311 @@
+use alloc::vec::Vec;
+use core::{marker::PhantomData, ops::Range};
+
+use super::parse_options::case_of_char;
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseToNumber, ChineseToNumberError,
+    chinese_characters::{ChineseExponent, ChineseNumber, ChineseSign},
+};
+
+/// 在文字中找到的中文數字。
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChineseNumberMatch<T> {
+    /// 中文數字在文字中的位元組範圍。
+    pub range: Range<usize>,
+    /// 中文數字的數值。
+    pub value: T,
+}
+
+/// 依序找出文字中的中文數字的疊代器。
+///
+/// 中文數字以數字字元（例如「三」、「兩」、「十」）開頭，前面可以有「負」，後面接著連續的數字字元和單位字元。沒有單位的連續數字（例如「二〇二六」）會被逐位轉換成一個數值。如果整段無法轉換，則取其中可以轉換的最長開頭部份。「分」和「角」預設不會被當作單位，例如「三分鐘」只取「三」，需要時可以使用 `with_money_units`。阿拉伯數字以及單獨一個大寫數字（例如「參加」的「參」）不會被當作中文數字。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseCountMethod, ChineseNumberScanner};
+///
+/// let text = "共有三千二百人參加，其中兩百人來自外地。";
+///
+/// let matches: Vec<_> = ChineseNumberScanner::<u32>::new(text, ChineseCountMethod::TenThousand)
+///     .map(|m| (&text[m.range], m.value))
+///     .collect();
+///
+/// assert_eq!(vec![("三千二百", 3200), ("兩百", 200)], matches);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChineseNumberScanner<'a, T> {
+    text:        &'a str,
+    method:      ChineseCountMethod,
+    money_units: bool,
+    position:    usize,
+    phantom:     PhantomData<T>,
+}
+
+impl<'a, T> ChineseNumberScanner<'a, T> {
+    /// 建立從 `text` 的開頭開始尋找中文數字的疊代器。
+    #[inline]
+    pub const fn new(text: &'a str, method: ChineseCountMethod) -> Self {
+        Self {
+            text,
+            method,
+            money_units: false,
+            position: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// 建立從 `text` 的開頭開始尋找中文數字的疊代器，並將金額單位「分」和「角」當作中文數字的一部份。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseNumberScanner};
+    ///
+    /// let text = "找了三角五分";
+    ///
+    /// let values: Vec<f64> =
+    ///     ChineseNumberScanner::with_money_units(text, ChineseCountMethod::TenThousand)
+    ///         .map(|m| m.value)
+    ///         .collect();
+    ///
+    /// assert_eq!(vec![0.35], values);
+    /// ```
+    #[inline]
+    pub const fn with_money_units(text: &'a str, method: ChineseCountMethod) -> Self {
+        Self {
+            text,
+            method,
+            money_units: true,
+            position: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChineseNumberScanner<'a, T>
+where
+    &'a str: ChineseToNumber<T>,
+{
+    type Item = ChineseNumberMatch<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.text;
+
+        while let Some(c) = text[self.position..].chars().next() {
+            let start = self.position;
+
+            self.position += c.len_utf8();
+
+            let boundaries = run_boundaries(text, start, self.money_units);
+
+            // Digits without units, such as years, are read one by one as a whole.
+            if let Some(&end) = boundaries.last()
+                && is_digit_sequence(&text[start..end])
+            {
+                self.position = end;
+
+                match (&text[start..end]).to_number_naive() {
+                    Ok(value) => {
+                        return Some(ChineseNumberMatch {
+                            range: start..end,
+                            value,
+                        });
+                    },
+                    Err(_) => continue,
+                }
+            }
+
+            // Try the longest candidate first.
+            for &end in boundaries.iter().rev() {
+                let candidate = &text[start..end];
+
+                if is_single_upper_case_digit(candidate) {
+                    continue;
+                }
+
+                match candidate.to_number(self.method) {
+                    Ok(value) => {
+                        self.position = end;
+
+                        return Some(ChineseNumberMatch {
+                            range: start..end,
+                            value,
+                        });
+                    },
+                    // A shorter candidate would be a different number, so the candidate is skipped.
+                    Err(ChineseToNumberError::Overflow | ChineseToNumberError::Underflow) => {
+                        self.position = end;
+
+                        break;
+                    },
+                    Err(_) => (),
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// 找出文字中所有的中文數字。無法轉換的部份會被略過。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{scan_chinese_numbers, ChineseCountMethod};
+///
+/// let text = "負三度到十二萬五千";
+///
+/// let values: Vec<i64> =
+///     scan_chinese_numbers(text, ChineseCountMethod::TenThousand).map(|m| m.value).collect();
+///
+/// assert_eq!(vec![-3, 125000], values);
+/// ```
+#[inline]
+pub fn scan_chinese_numbers<'a, T>(
+    text: &'a str,
+    method: ChineseCountMethod,
+) -> ChineseNumberScanner<'a, T>
+where
+    &'a str: ChineseToNumber<T>, {
+    ChineseNumberScanner::new(text, method)
+}
+
+pub(crate) fn run_boundaries(s: &str, start: usize, money_units: bool) -> Vec<usize> {
+    let mut boundaries = Vec::new();
+    let mut position = start;
+
+    let mut chars = s[start..].chars();
+
+    if let Some(c) = chars.next()
+        && ChineseSign::from_char(c) == Some(ChineseSign::負)
+    {
+        if !chars.next().is_some_and(is_digit) {
+            return boundaries;
+        }
+
+        position += c.len_utf8();
+    }
+
+    if !s[position..].chars().next().is_some_and(is_digit) {
+        return boundaries;
+    }
+
+    while let Some(c) = s[position..].chars().next() {
+        if is_digit(c) {
+            position += c.len_utf8();
+        } else if let Some((exp, end)) = unit_at(s, position)
+            && (money_units || exp >= ChineseExponent::個)
+        {
+            position = end;
+        } else {
+            break;
+        }
+
+        boundaries.push(position);
+    }
+
+    boundaries
+}
+
+pub(crate) fn parse_prefix<'a, T>(
+    s: &'a str,
+    method: ChineseCountMethod,
+) -> Result<(T, usize), ChineseToNumberError>
+where
+    &'a str: ChineseToNumber<T>, {
+    let mut error = None;
+
+    for &end in prefix_boundaries(s).iter().rev() {
+        match (&s[..end]).to_number(method) {
+            Ok(value) => return Ok((value, end)),
+            // A shorter candidate would be a different number.
+            Err(e @ (ChineseToNumberError::Overflow | ChineseToNumberError::Underflow)) => {
+                return Err(e);
+            },
+            Err(e) => {
+                error.get_or_insert(e);
+            },
+        }
+    }
+
+    Err(error.unwrap_or(ChineseToNumberError::ChineseNumberEmpty))
+}
+
+fn prefix_boundaries(s: &str) -> Vec<usize> {
+    let mut boundaries = Vec::new();
+    let mut position = 0;
+
+    if let Some(c) = s.chars().next()
+        && ChineseSign::from_char(c) == Some(ChineseSign::負)
+    {
+        position += c.len_utf8();
+    }
+
+    while let Some(c) = s[position..].chars().next() {
+        if ChineseNumber::from_char(c).is_some() {
+            position += c.len_utf8();
+        } else if let Some(end) = unit_end(s, position) {
+            position = end;
+        } else {
+            break;
+        }
+
+        boundaries.push(position);
+    }
+
+    if boundaries.is_empty() {
+        boundaries.push(position + s[position..].chars().next().map_or(0, char::len_utf8));
+    }
+
+    boundaries
+}
+
+#[inline]
+fn is_digit(c: char) -> bool {
+    !c.is_ascii() && ChineseNumber::from_char(c).is_some()
+}
+
+fn is_digit_sequence(s: &str) -> bool {
+    let digits = s.strip_prefix(['負', '负']).unwrap_or(s);
+
+    digits.chars().nth(1).is_some()
+        && digits.chars().all(|c| {
+            is_digit(c) && ChineseNumber::from_char(c).is_some_and(|n| n != ChineseNumber::十)
+        })
+}
+
+pub(crate) fn is_single_upper_case_digit(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => {
+            ChineseNumber::from_char(c).is_some() && case_of_char(c) == Some(ChineseCase::Upper)
+        },
+        _ => false,
+    }
+}
+
+#[inline]
+fn unit_end(s: &str, index: usize) -> Option<usize> {
+    unit_at(s, index).map(|(_, end)| end)
+}
+
+pub(crate) fn unit_at(s: &str, index: usize) -> Option<(ChineseExponent, usize)> {
+    let mut end = index;
+    let mut longest = None;
+
+    // The longest units, such as 不可思議, span four characters.
+    for (count, c) in s[index..].chars().take(4).enumerate() {
+        end += c.len_utf8();
+
+        if let Some((exp, length)) = ChineseExponent::from_chars_rev(s[index..end].chars().rev())
+            && length == count + 1
+        {
+            longest = Some((exp, end));
+        }
+    }
+
+    longest
+}
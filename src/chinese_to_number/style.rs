@@ -0,0 +1,112 @@
+use super::from_chinese_to_ast;
+use crate::{ChineseCase, ChineseCountMethod, ChineseVariant, chinese_characters::ChineseGlyphStyle};
+
+/// 偵測到的寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseDetected<T> {
+    /// 沒有可以用來判斷的字元。
+    Unknown,
+    /// 只使用了一種寫法。
+    Uniform(T),
+    /// 混用了多種寫法。
+    Mixed,
+}
+
+impl<T: PartialEq> ChineseDetected<T> {
+    #[inline]
+    fn add(self, value: T) -> Self {
+        match self {
+            Self::Unknown => Self::Uniform(value),
+            Self::Uniform(v) if v == value => Self::Uniform(v),
+            _ => Self::Mixed,
+        }
+    }
+}
+
+/// 中文數字的寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseNumberStyle {
+    /// 中文數字的字體。「一」、「十」之類繁簡相同的字元不會被當作依據。
+    pub chinese_variant: ChineseDetected<ChineseVariant>,
+    /// 中文數字的大小寫。「零」、「萬」之類大小寫相同的字元不會被當作依據。
+    pub chinese_case:    ChineseDetected<ChineseCase>,
+    /// 用來表示零的字元，為「零」、「〇」或「0」。
+    pub zero:            ChineseDetected<char>,
+    /// 是否使用「兩」或「两」表示二。
+    pub liang:           bool,
+    /// 是否省略了最後的單位，例如「一百二」。
+    pub abbreviated:     bool,
+}
+
+/// 偵測中文數字所使用的字體、大小寫、零的字元，以及是否使用「兩」和省略最後的單位，以便用相同的寫法輸出數字。字體和大小寫的判斷依據和解析時所接受的字元相同。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     detect_number_style, ChineseCase, ChineseCountMethod, ChineseDetected, ChineseVariant,
+/// };
+///
+/// let style = detect_number_style("壹佰贰拾", ChineseCountMethod::TenThousand);
+///
+/// assert_eq!(ChineseDetected::Uniform(ChineseVariant::Simple), style.chinese_variant);
+/// assert_eq!(ChineseDetected::Uniform(ChineseCase::Upper), style.chinese_case);
+/// assert_eq!(ChineseDetected::Unknown, style.zero);
+///
+/// let style = detect_number_style("兩萬〇三百万", ChineseCountMethod::TenThousand);
+///
+/// assert_eq!(ChineseDetected::Mixed, style.chinese_variant);
+/// assert_eq!(ChineseDetected::Uniform('〇'), style.zero);
+/// assert!(style.liang);
+/// assert!(!style.abbreviated);
+///
+/// assert!(detect_number_style("一百二", ChineseCountMethod::TenThousand).abbreviated);
+/// ```
+pub fn detect_number_style<S: AsRef<str>>(
+    chinese_number: S,
+    method: ChineseCountMethod,
+) -> ChineseNumberStyle {
+    let chinese_number = chinese_number.as_ref().trim();
+
+    let mut chinese_variant = ChineseDetected::Unknown;
+    let mut chinese_case = ChineseDetected::Unknown;
+    let mut zero = ChineseDetected::Unknown;
+    let mut liang = false;
+
+    for c in chinese_number.chars() {
+        let Some(style) = ChineseGlyphStyle::from_char(c) else {
+            continue;
+        };
+
+        if let Some(variant) = style.variant {
+            chinese_variant = chinese_variant.add(variant);
+        }
+
+        if let Some(case) = style.case {
+            chinese_case = chinese_case.add(case);
+        }
+
+        match c {
+            '零' | '〇' | '0' => zero = zero.add(c),
+            '兩' | '两' => liang = true,
+            _ => (),
+        }
+    }
+
+    // Only the integer part can be abbreviated.
+    let integer = chinese_number
+        .trim_start_matches(['負', '负'])
+        .split(['點', '点'])
+        .next()
+        .unwrap_or_default();
+
+    let abbreviated = from_chinese_to_ast(integer, method).is_ok_and(|ast| ast.has_abbreviation());
+
+    ChineseNumberStyle {
+        chinese_variant,
+        chinese_case,
+        zero,
+        liang,
+        abbreviated,
+    }
+}
@@ -23,6 +23,73 @@ pub trait ChineseToNumber<T> {
     /// assert_eq!(123456789u64, "一二三四五六七八九".to_number_naive().unwrap());
     /// ```
     fn to_number_naive(&self) -> Result<T, ChineseToNumberError>;
+
+    /// 只接受 `options` 允許的寫法，將中文數字轉成數值。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseParseOptions, ChineseToNumber};
+    ///
+    /// let options = ChineseParseOptions::strict();
+    ///
+    /// # #[cfg(feature = "number-to-chinese")]
+    /// assert_eq!(
+    ///     102u64,
+    ///     "壹佰零貳".to_number_with_options(ChineseCountMethod::TenThousand, options).unwrap()
+    /// );
+    /// assert!(
+    ///     ChineseToNumber::<u64>::to_number_with_options(
+    ///         &"壹佰零二",
+    ///         ChineseCountMethod::TenThousand,
+    ///         options
+    ///     )
+    ///     .is_err()
+    /// );
+    /// ```
+    #[inline]
+    fn to_number_with_options(
+        &self,
+        method: ChineseCountMethod,
+        options: ChineseParseOptions,
+    ) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>, {
+        options.check(self)?;
+
+        let value = self.to_number(method)?;
+
+        options.check_canonical(self.as_ref(), method)?;
+
+        Ok(value)
+    }
+
+    /// 將字串開頭最長的中文數字轉成數值，並回傳數值和該中文數字的位元組長度。中文數字後面可以有其它文字。如果中文數字超出數值型別的範圍，則回傳錯誤，而不會改用較短的中文數字。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseToNumber};
+    ///
+    /// assert_eq!(
+    ///     (320u32, 12),
+    ///     "三百二十個蘋果".to_number_prefix(ChineseCountMethod::TenThousand).unwrap()
+    /// );
+    /// assert!(
+    ///     ChineseToNumber::<u32>::to_number_prefix(&"蘋果", ChineseCountMethod::TenThousand)
+    ///         .is_err()
+    /// );
+    /// ```
+    #[inline]
+    fn to_number_prefix<'a>(
+        &'a self,
+        method: ChineseCountMethod,
+    ) -> Result<(T, usize), ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        &'a str: ChineseToNumber<T>, {
+        parse_prefix(self.as_ref(), method)
+    }
 }
 
 impl<T: AsRef<str>> ChineseToNumber<u8> for T {
@@ -57,6 +124,7 @@ impl<T: AsRef<str>> ChineseToNumber<u32> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_u32_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_u32_middle(self),
             ChineseCountMethod::High => from_chinese_to_u32_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_u32_mainland(self),
         }
     }
 
@@ -74,6 +142,7 @@ impl<T: AsRef<str>> ChineseToNumber<u64> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_u64_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_u64_middle(self),
             ChineseCountMethod::High => from_chinese_to_u64_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_u64_mainland(self),
         }
     }
 
@@ -91,6 +160,7 @@ impl<T: AsRef<str>> ChineseToNumber<u128> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_u128_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_u128_middle(self),
             ChineseCountMethod::High => from_chinese_to_u128_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_u128_mainland(self),
         }
     }
 
@@ -108,6 +178,7 @@ impl<T: AsRef<str>> ChineseToNumber<usize> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_usize_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_usize_middle(self),
             ChineseCountMethod::High => from_chinese_to_usize_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_usize_mainland(self),
         }
     }
 
@@ -149,6 +220,7 @@ impl<T: AsRef<str>> ChineseToNumber<i32> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_i32_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_i32_middle(self),
             ChineseCountMethod::High => from_chinese_to_i32_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_i32_mainland(self),
         }
     }
 
@@ -166,6 +238,7 @@ impl<T: AsRef<str>> ChineseToNumber<i64> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_i64_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_i64_middle(self),
             ChineseCountMethod::High => from_chinese_to_i64_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_i64_mainland(self),
         }
     }
 
@@ -183,6 +256,7 @@ impl<T: AsRef<str>> ChineseToNumber<i128> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_i128_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_i128_middle(self),
             ChineseCountMethod::High => from_chinese_to_i128_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_i128_mainland(self),
         }
     }
 
@@ -200,6 +274,7 @@ impl<T: AsRef<str>> ChineseToNumber<isize> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_isize_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_isize_middle(self),
             ChineseCountMethod::High => from_chinese_to_isize_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_isize_mainland(self),
         }
     }
 
@@ -217,6 +292,7 @@ impl<T: AsRef<str>> ChineseToNumber<f32> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_f32_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_f32_middle(self),
             ChineseCountMethod::High => from_chinese_to_f32_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_f32_mainland(self),
         }
     }
 
@@ -234,6 +310,7 @@ impl<T: AsRef<str>> ChineseToNumber<f64> for T {
             ChineseCountMethod::TenThousand => from_chinese_to_f64_ten_thousand(self),
             ChineseCountMethod::Middle => from_chinese_to_f64_middle(self),
             ChineseCountMethod::High => from_chinese_to_f64_high(self),
+            ChineseCountMethod::Mainland => from_chinese_to_f64_mainland(self),
         }
     }
 
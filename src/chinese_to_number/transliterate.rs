@@ -0,0 +1,89 @@
+use alloc::string::String;
+
+use super::scanner::unit_at;
+use crate::{
+    ChineseCase, ChineseVariant,
+    chinese_characters::{
+        ChineseApproximation, ChineseDecimalUnit, ChineseNumber, ChinesePoint, ChineseSign,
+    },
+};
+
+/// 逐字將中文數字換成其它字體或大小寫，不會解析數值，因此沒有數值大小的限制，也會保留原本的寫法，例如「兩」、省略的單位和空白字元。
+///
+/// * 小寫的「兩」會換成對應字體的「兩」或「两」，大寫時則換成「貳」或「贰」。
+/// * 小寫的「〇」會被保留，大寫時則換成「零」。
+/// * 阿拉伯數字和其它不屬於中文數字的字元會保持原樣。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{transliterate, ChineseCase, ChineseVariant};
+///
+/// assert_eq!(
+///     "一百二十三万",
+///     transliterate("壹佰貳拾參萬", ChineseVariant::Simple, ChineseCase::Lower)
+/// );
+/// assert_eq!(
+///     "兩千〇五",
+///     transliterate("两千〇五", ChineseVariant::Traditional, ChineseCase::Lower)
+/// );
+/// assert_eq!(
+///     "貳仟零伍",
+///     transliterate("两千〇五", ChineseVariant::Traditional, ChineseCase::Upper)
+/// );
+/// assert_eq!(
+///     "负三恒河沙点五",
+///     transliterate("負三恆河沙點五", ChineseVariant::Simple, ChineseCase::Lower)
+/// );
+/// ```
+pub fn transliterate<S: AsRef<str>>(
+    chinese_number: S,
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+) -> String {
+    let s = chinese_number.as_ref();
+
+    let mut result = String::with_capacity(s.len());
+    let mut position = 0;
+
+    while let Some(c) = s[position..].chars().next() {
+        // Units are read first, since some of them span several characters.
+        if let Some((exp, end)) = unit_at(s, position) {
+            result.push_str(exp.to_str(chinese_variant, chinese_case));
+
+            position = end;
+
+            continue;
+        }
+
+        match ChineseNumber::from_char(c) {
+            _ if c.is_ascii_digit() => result.push(c),
+            Some(ChineseNumber::零) if c == '〇' && chinese_case == ChineseCase::Lower => {
+                result.push(c)
+            },
+            Some(ChineseNumber::二) if matches!(c, '兩' | '两') => {
+                result.push_str(
+                    ChineseNumber::二.to_measure_word_str(chinese_variant, chinese_case),
+                )
+            },
+            Some(number) => result.push_str(number.to_str(chinese_variant, chinese_case)),
+            None => {
+                if let Some(unit) = ChineseDecimalUnit::from_char(c) {
+                    result.push_str(unit.to_str(chinese_variant));
+                } else if let Some(sign) = ChineseSign::from_char(c) {
+                    result.push_str(sign.to_str(chinese_variant));
+                } else if ChinesePoint::from_char(c).is_some() {
+                    result.push_str(ChinesePoint::to_str(chinese_variant));
+                } else if let Some(approximation) = ChineseApproximation::from_char(c) {
+                    result.push_str(approximation.to_str(chinese_variant));
+                } else {
+                    result.push(c);
+                }
+            },
+        }
+
+        position += c.len_utf8();
+    }
+
+    result
+}
@@ -23,6 +23,7 @@ assert_eq!("一極二載三正四澗五溝六穰七秭八垓九京零一億二
 assert_eq!("十二穰三千四百五十六秭七千八百九十垓一千二百三十四京五千六百七十八兆九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
 assert_eq!("十二萬三千四百五十六京七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::Middle).unwrap());
 assert_eq!("十二萬三千四百五十六億七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::High).unwrap());
+assert_eq!("一万亿", 1000000000000u64.to_chinese(ChineseVariant::Simple, ChineseCase::Lower, ChineseCountMethod::Mainland).unwrap());
 
 assert_eq!("一角二分", 0.12f64.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
 
@@ -37,6 +38,7 @@ assert_eq!(1000000u64, "一兆".to_number(ChineseCountMethod::Low).unwrap());
 assert_eq!(1000000000000u64, "一兆".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(10000000000000000u64, "一兆".to_number(ChineseCountMethod::Middle).unwrap());
 assert_eq!(10000000000000000u64, "一兆".to_number(ChineseCountMethod::High).unwrap());
+assert_eq!(1000000u64, "一兆".to_number(ChineseCountMethod::Mainland).unwrap());
 
 assert_eq!(120u64, "一百二".to_number(ChineseCountMethod::TenThousand).unwrap());
 assert_eq!(2300u64, "兩千三".to_number(ChineseCountMethod::TenThousand).unwrap());
@@ -46,6 +48,30 @@ assert_eq!(150000000u64, "一億五".to_number(ChineseCountMethod::TenThousand).
 # }
 ```
 
+## Serde
+
+Enable the `serde` feature to serialize numbers as Chinese numbers, or to deserialize Chinese numbers to numbers, with `#[serde(with = "...")]`. The modules are named after the count method and the case, and the ones under `chinese_number::serde::simple` output Simple Chinese. The `number_or_chinese` modules accept numbers as well.
+
+```rust
+# #[cfg(feature = "serde")]
+# {
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Payment {
+    #[serde(rename = "金額", with = "chinese_number::serde::ten_thousand_upper")]
+    amount: u64,
+    #[serde(with = "chinese_number::serde::ten_thousand_lower::number_or_chinese")]
+    count:  u32,
+}
+
+let payment: Payment = serde_json::from_str(r#"{"金額":"壹仟貳佰","count":3}"#).unwrap();
+
+assert_eq!(1200, payment.amount);
+assert_eq!(r#"{"金額":"壹仟貳佰","count":"三"}"#, serde_json::to_string(&payment).unwrap());
+# }
+```
+
 ## No Std
 
 Disable the default features to compile this crate without std.
@@ -69,15 +95,25 @@ mod number_to_chinese;
 #[cfg(feature = "chinese-to-number")]
 mod chinese_to_number;
 
+mod chinese_approximate_number;
 mod chinese_case;
 #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
 mod chinese_characters;
 mod chinese_count_method;
+mod chinese_largest_unit;
+mod chinese_range;
+#[cfg(feature = "serde")]
+pub mod serde;
 
+pub use chinese_approximate_number::*;
 pub use chinese_case::*;
 #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+pub use chinese_characters::{ChineseExponent, ChineseSign};
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
 pub(crate) use chinese_characters::*;
 pub use chinese_count_method::*;
+pub use chinese_largest_unit::*;
+pub use chinese_range::*;
 #[cfg(feature = "chinese-to-number")]
 pub use chinese_to_number::*;
 pub use chinese_variant::*;
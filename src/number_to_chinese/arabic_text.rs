@@ -0,0 +1,214 @@
+use alloc::string::String;
+
+use super::{NumberToChinese, prepend_negative_sign};
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseVariant,
+    chinese_characters::{ChineseNumber, ChinesePoint},
+};
+
+/// 將文字中的阿拉伯數字換成中文數字時的設定。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ArabicToChineseOptions {
+    /// 輸出的中文數字的字體。
+    pub chinese_variant: ChineseVariant,
+    /// 輸出的中文數字的大小寫。
+    pub chinese_case:    ChineseCase,
+    /// 逐位讀出以外的數字所使用的算術類型。
+    pub method:          ChineseCountMethod,
+}
+
+impl Default for ArabicToChineseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            chinese_variant: ChineseVariant::Traditional,
+            chinese_case:    ChineseCase::Lower,
+            method:          ChineseCountMethod::TenThousand,
+        }
+    }
+}
+
+/// 將文字中的阿拉伯數字換成可以直接唸出的中文數字。
+///
+/// * 以「-」分段且有七位以上的數字（例如電話號碼）、以「0」開頭的多位數，以及後面接著「年」的數字，會逐位讀出。
+/// * 其它的數字會依照 `options.method` 計算數值，並接受千分位的「,」、開頭的「-」和以「點」讀出的小數部份。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_arabic_text_to_chinese, ArabicToChineseOptions, ChineseCase, ChineseCountMethod,
+///     ChineseVariant,
+/// };
+///
+/// assert_eq!(
+///     "二零二六年十月十八日 價格$一千二百三十四點五 電話零九一二三四五六七八",
+///     from_arabic_text_to_chinese(
+///         "2026年10月18日 價格$1,234.5 電話0912-345-678",
+///         ArabicToChineseOptions::default()
+///     )
+/// );
+///
+/// let options = ArabicToChineseOptions {
+///     chinese_variant: ChineseVariant::Simple,
+///     chinese_case:    ChineseCase::Upper,
+///     method:          ChineseCountMethod::TenThousand,
+/// };
+///
+/// assert_eq!("负叁万", from_arabic_text_to_chinese("-30000", options));
+/// ```
+pub fn from_arabic_text_to_chinese<S: AsRef<str>>(
+    text: S,
+    options: ArabicToChineseOptions,
+) -> String {
+    let text = text.as_ref();
+
+    let mut result = String::with_capacity(text.len() * 3);
+    let mut position = 0;
+
+    while let Some(c) = text[position..].chars().next() {
+        match ArabicNumber::read(text, position) {
+            Some(number) => {
+                number.push_chinese(&mut result, options);
+
+                position = number.end;
+            },
+            None => {
+                result.push(c);
+
+                position += c.len_utf8();
+            },
+        }
+    }
+
+    result
+}
+
+const MIN_PHONE_DIGITS: usize = 7;
+
+struct ArabicNumber<'a> {
+    negative:  bool,
+    integer:   String,
+    fraction:  Option<&'a str>,
+    digitwise: bool,
+    end:       usize,
+}
+
+impl<'a> ArabicNumber<'a> {
+    fn read(s: &'a str, start: usize) -> Option<Self> {
+        let bytes = s.as_bytes();
+
+        let digits_from = |index: usize| {
+            bytes[index.min(bytes.len())..].iter().take_while(|b| b.is_ascii_digit()).count()
+        };
+
+        let mut index = start;
+
+        // A minus sign is only a sign when it is not between letters or digits, such as in "A-1".
+        let negative = bytes[index] == b'-'
+            && digits_from(index + 1) > 0
+            && !s[..start].chars().next_back().is_some_and(|c| c.is_ascii_alphanumeric());
+
+        if negative {
+            index += 1;
+        }
+
+        let length = digits_from(index);
+
+        if length == 0 {
+            return None;
+        }
+
+        let mut integer = String::from(&s[index..index + length]);
+
+        index += length;
+
+        // Groups joined by hyphens are read as a phone number when they have enough digits, otherwise they are something like a range, as in "10-12".
+        let mut phone = integer.clone();
+        let mut phone_end = index;
+
+        while bytes.get(phone_end) == Some(&b'-') && digits_from(phone_end + 1) > 0 {
+            let length = digits_from(phone_end + 1);
+
+            phone.push_str(&s[phone_end + 1..phone_end + 1 + length]);
+
+            phone_end += 1 + length;
+        }
+
+        let hyphenated = phone_end > index && phone.len() >= MIN_PHONE_DIGITS;
+        let mut grouped = false;
+
+        if hyphenated {
+            integer = phone;
+            index = phone_end;
+        } else {
+            while bytes.get(index) == Some(&b',') && digits_from(index + 1) == 3 {
+                integer.push_str(&s[index + 1..index + 4]);
+
+                grouped = true;
+                index += 4;
+            }
+        }
+
+        let mut fraction = None;
+
+        if !hyphenated && bytes.get(index) == Some(&b'.') {
+            let length = digits_from(index + 1);
+
+            if length > 0 {
+                fraction = Some(&s[index + 1..index + 1 + length]);
+
+                index += 1 + length;
+            }
+        }
+
+        let digitwise = hyphenated
+            || (!grouped && integer.len() > 1 && integer.starts_with('0'))
+            || (!grouped && fraction.is_none() && s[index..].starts_with('年'));
+
+        Some(Self {
+            negative,
+            integer,
+            fraction,
+            digitwise,
+            end: index,
+        })
+    }
+
+    fn push_chinese(&self, result: &mut String, options: ArabicToChineseOptions) {
+        let mut s = String::new();
+
+        let counted = if self.digitwise {
+            None
+        } else {
+            self.integer.parse::<u128>().ok().and_then(|value| {
+                value.to_chinese(options.chinese_variant, options.chinese_case, options.method).ok()
+            })
+        };
+
+        match counted {
+            Some(counted) => s.push_str(&counted),
+            None => push_digits(&mut s, &self.integer, options),
+        }
+
+        if let Some(fraction) = self.fraction {
+            s.push_str(ChinesePoint::to_str(options.chinese_variant));
+
+            push_digits(&mut s, fraction, options);
+        }
+
+        if self.negative {
+            prepend_negative_sign(options.chinese_variant, &mut s);
+        }
+
+        result.push_str(&s);
+    }
+}
+
+fn push_digits(s: &mut String, digits: &str, options: ArabicToChineseOptions) {
+    for b in digits.bytes() {
+        let number = unsafe { ChineseNumber::from_ordinal_unsafe(b - b'0') };
+
+        s.push_str(number.to_str(options.chinese_variant, options.chinese_case));
+    }
+}
@@ -10,8 +10,8 @@ use num_traits::float::FloatCore;
 use num_traits::{FromPrimitive, ToPrimitive, Zero};
 
 use crate::{
-    ChineseCase, ChineseCountMethod, ChineseExponent, ChineseNumber, ChineseSign, ChineseVariant,
-    NumberToChineseError,
+    ChineseCase, ChineseCountMethod, ChineseDecimalUnit, ChineseExponent, ChineseNumber,
+    ChineseRangeSeparator, ChineseSign, ChineseVariant, NumberToChineseError,
 };
 
 /// Stores short static text chunks on the stack and writes them once in reverse order.
@@ -127,9 +127,9 @@ pub(crate) fn unsigned_integer_to_chinese_low(
     dependent: bool,
     mut value: u128,
 ) -> String {
-    debug_assert!(value < 1_0000_0000_0000_0000);
+    debug_assert!(value < 10_0000_0000_0000_0000_0000);
 
-    let mut chunks = ReverseChunkBuffer::<48>::new();
+    let mut chunks = ReverseChunkBuffer::<64>::new();
 
     let mut lower_d = (value % 10) as u8;
     value /= 10;
@@ -383,7 +383,7 @@ pub(crate) fn big_unsigned_integer_to_chinese_ten_thousand(
     dependent: bool,
     value: BigUint,
 ) -> String {
-    debug_assert!(value < BigUint::from(10u8).pow(52));
+    debug_assert!(value < BigUint::from(10u8).pow(72));
 
     grouped_big_unsigned_integer_to_chinese::<1_0000, 1000>(
         chinese_variant,
@@ -417,7 +417,7 @@ pub(crate) fn big_unsigned_integer_to_chinese_middle(
     dependent: bool,
     value: BigUint,
 ) -> String {
-    debug_assert!(value < BigUint::from(10u8).pow(96));
+    debug_assert!(value < BigUint::from(10u8).pow(136));
 
     grouped_big_unsigned_integer_to_chinese::<1_0000_0000, 1000_0000>(
         chinese_variant,
@@ -588,9 +588,107 @@ pub(crate) fn big_unsigned_integer_to_chinese_high(
     s
 }
 
+pub(crate) fn unsigned_integer_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: u128,
+    largest_unit: ChineseExponent,
+) -> String {
+    debug_assert!(largest_unit >= ChineseExponent::萬);
+
+    let w = match 1_0000u128
+        .checked_pow((largest_unit.ordinal() - ChineseExponent::千.ordinal()) as u32)
+    {
+        Some(w) if value >= w => w,
+        _ => {
+            return unsigned_integer_to_chinese_ten_thousand(
+                chinese_variant,
+                chinese_case,
+                dependent,
+                value,
+            );
+        },
+    };
+
+    let low = value % w;
+    let high = value / w;
+
+    let mut s = unsigned_integer_to_chinese_compound(
+        chinese_variant,
+        chinese_case,
+        dependent,
+        high,
+        largest_unit,
+    );
+
+    s.push_str(largest_unit.to_str(chinese_variant, chinese_case));
+
+    if low > 0 {
+        if low < w / 10 {
+            s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+        }
+
+        s.push_str(
+            unsigned_integer_to_chinese_ten_thousand(chinese_variant, chinese_case, true, low)
+                .as_str(),
+        );
+    }
+
+    s
+}
+
+pub(crate) fn big_unsigned_integer_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: BigUint,
+    largest_unit: ChineseExponent,
+) -> String {
+    debug_assert!(largest_unit >= ChineseExponent::萬);
+
+    let w = BigUint::from(1_0000u32)
+        .pow((largest_unit.ordinal() - ChineseExponent::千.ordinal()) as u32);
+
+    if value < w {
+        return big_unsigned_integer_to_chinese_ten_thousand(
+            chinese_variant,
+            chinese_case,
+            dependent,
+            value,
+        );
+    }
+
+    let low = value.clone() % &w;
+    let high = value / &w;
+
+    let mut s = big_unsigned_integer_to_chinese_compound(
+        chinese_variant,
+        chinese_case,
+        dependent,
+        high,
+        largest_unit,
+    );
+
+    s.push_str(largest_unit.to_str(chinese_variant, chinese_case));
+
+    if !low.is_zero() {
+        if low < w / BigUint::from(10u8) {
+            s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+        }
+
+        s.push_str(
+            big_unsigned_integer_to_chinese_ten_thousand(chinese_variant, chinese_case, true, low)
+                .as_str(),
+        );
+    }
+
+    s
+}
+
 /// Converts a non-zero `BigUint` integer part with the selected Chinese count method.
 #[inline]
-fn big_integer_to_chinese_by_method(
+pub(crate) fn big_integer_to_chinese_by_method(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     method: ChineseCountMethod,
@@ -602,7 +700,7 @@ fn big_integer_to_chinese_by_method(
 
     match method {
         ChineseCountMethod::Low => {
-            let limit = BigUint::from(1_0000_0000_0000_0000u64);
+            let limit = BigUint::from(10_0000_0000_0000_0000_0000u128);
 
             if integer >= &limit {
                 return Err(NumberToChineseError::Overflow);
@@ -616,7 +714,7 @@ fn big_integer_to_chinese_by_method(
             ))
         },
         ChineseCountMethod::TenThousand => {
-            let limit = big_10.pow(52);
+            let limit = big_10.pow(72);
 
             if integer >= &limit {
                 return Err(NumberToChineseError::Overflow);
@@ -630,7 +728,7 @@ fn big_integer_to_chinese_by_method(
             ))
         },
         ChineseCountMethod::Middle => {
-            let limit = big_10.pow(96);
+            let limit = big_10.pow(136);
 
             if integer >= &limit {
                 return Err(NumberToChineseError::Overflow);
@@ -649,12 +747,19 @@ fn big_integer_to_chinese_by_method(
             false,
             integer.clone(),
         )),
+        ChineseCountMethod::Mainland => Ok(big_unsigned_integer_to_chinese_compound(
+            chinese_variant,
+            chinese_case,
+            false,
+            integer.clone(),
+            ChineseExponent::億,
+        )),
     }
 }
 
 /// Appends the rounded money fraction with 角 and 分 units.
 #[inline]
-fn push_money_fraction(
+pub(crate) fn push_money_fraction(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     s: &mut String,
@@ -713,3 +818,175 @@ pub(crate) fn positive_float_to_chinese(
 
     Ok(s)
 }
+
+pub(crate) fn positive_float_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    largest_unit: ChineseExponent,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    let (integer, fraction) = split_positive_f64(value)?;
+
+    let mut s = if integer.is_zero() {
+        String::new()
+    } else {
+        big_unsigned_integer_to_chinese_compound(
+            chinese_variant,
+            chinese_case,
+            false,
+            integer,
+            largest_unit,
+        )
+    };
+
+    if fraction > 0 {
+        push_money_fraction(chinese_variant, chinese_case, &mut s, fraction);
+    } else if s.is_empty() {
+        s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+    }
+
+    Ok(s)
+}
+
+#[inline]
+pub(crate) fn f32_to_f64_shortest(value: f32) -> f64 {
+    let wide = value as f64;
+
+    // An integer is exact in both types, so only a fraction has to be rounded.
+    if !wide.is_finite() || wide.fract() == 0.0 {
+        return wide;
+    }
+
+    let abs = wide.abs();
+    let mut magnitude = 0;
+
+    while 10f64.powi(magnitude) <= abs {
+        magnitude += 1;
+    }
+
+    while 10f64.powi(magnitude - 1) > abs {
+        magnitude -= 1;
+    }
+
+    // 9 significant digits are enough to tell every f32 apart from its neighbors.
+    for digits in 1..=9 {
+        let shift = digits - magnitude;
+        let scale = 10f64.powi(shift.abs());
+
+        let rounded = if shift >= 0 {
+            (wide * scale).round() / scale
+        } else {
+            (wide / scale).round() * scale
+        };
+
+        if rounded as f32 == value {
+            return rounded;
+        }
+    }
+
+    wide
+}
+
+#[inline]
+fn split_positive_f64_decimal_unit(value: f64) -> Result<(BigUint, u32), NumberToChineseError> {
+    debug_assert!(value.is_finite());
+    debug_assert!(value >= 0.0);
+
+    const SCALE: u32 = 1_0000_0000;
+
+    let mut integer = BigUint::from_f64(value.trunc()).ok_or(NumberToChineseError::Overflow)?;
+    let fraction = (value.fract() * SCALE as f64).round() as u32;
+
+    // Same as the money fraction, a rounded fraction of one whole unit must carry into the integer part.
+    let fraction = if fraction >= SCALE {
+        integer += BigUint::from(1u8);
+
+        0
+    } else {
+        fraction
+    };
+
+    Ok((integer, fraction))
+}
+
+pub(crate) fn positive_float_to_chinese_decimal_unit(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    let (integer, fraction) = split_positive_f64_decimal_unit(value)?;
+
+    let mut s = if integer.is_zero() {
+        String::new()
+    } else {
+        big_integer_to_chinese_by_method(chinese_variant, chinese_case, method, &integer)?
+    };
+
+    if fraction > 0 {
+        push_decimal_unit_fraction(chinese_variant, chinese_case, &mut s, fraction);
+    } else if integer.is_zero() {
+        s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+    }
+
+    Ok(s)
+}
+
+pub(crate) fn push_decimal_unit_fraction(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    s: &mut String,
+    mut fraction: u32,
+) {
+    debug_assert!(fraction < 1_0000_0000);
+
+    // A skipped position is written as a single 零, but only between two written parts.
+    let mut has_previous = !s.is_empty();
+    let mut pending_zero = false;
+    let mut divisor = 1000_0000u32;
+
+    for unit in ChineseDecimalUnit::variants() {
+        let digit = (fraction / divisor) as u8;
+
+        fraction %= divisor;
+        divisor /= 10;
+
+        if digit == 0 {
+            pending_zero = has_previous;
+        } else {
+            if pending_zero {
+                s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
+
+                pending_zero = false;
+            }
+
+            s.push_str(
+                unsafe { ChineseNumber::from_ordinal_unsafe(digit) }
+                    .to_str(chinese_variant, chinese_case),
+            );
+            s.push_str(unit.to_str(chinese_variant));
+
+            has_previous = true;
+        }
+
+        if fraction == 0 {
+            break;
+        }
+    }
+}
+
+pub(crate) fn join_range_chinese(
+    separator: ChineseRangeSeparator,
+    low: &str,
+    high: &str,
+) -> String {
+    let separator = separator.to_str();
+
+    let mut s = String::with_capacity(low.len() + separator.len() + high.len());
+
+    s.push_str(low);
+    s.push_str(separator);
+    s.push_str(high);
+
+    s
+}
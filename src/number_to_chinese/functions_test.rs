@@ -1,5 +1,7 @@
 #![cfg(test)]
 
+use num_bigint::BigUint;
+
 use super::*;
 
 #[test]
@@ -237,3 +239,103 @@ fn test_fraction_compat() {
     test!("五角五分", 0.55);
     test!("九十九九角九分", 99.99);
 }
+
+#[test]
+fn test_big_unsigned_integer_to_chinese_large_units() {
+    macro_rules! test {
+        ($expect:expr, $f:ident, $chinese_variant:expr, $exp:expr) => {
+            assert_eq!(
+                $expect,
+                $f($chinese_variant, ChineseCase::Lower, false, BigUint::from(10u8).pow($exp))
+            );
+        };
+    }
+
+    test!("一極", big_unsigned_integer_to_chinese_ten_thousand, ChineseVariant::Traditional, 48);
+    test!(
+        "一恆河沙",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        52
+    );
+    test!("一恒河沙", big_unsigned_integer_to_chinese_ten_thousand, ChineseVariant::Simple, 52);
+    test!(
+        "一阿僧祇",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        56
+    );
+    test!(
+        "一那由他",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        60
+    );
+    test!(
+        "一不可思議",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        64
+    );
+    test!(
+        "一無量大數",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        68
+    );
+    test!("一无量大数", big_unsigned_integer_to_chinese_ten_thousand, ChineseVariant::Simple, 68);
+    test!(
+        "一千無量大數",
+        big_unsigned_integer_to_chinese_ten_thousand,
+        ChineseVariant::Traditional,
+        71
+    );
+    test!("一恆河沙", big_unsigned_integer_to_chinese_middle, ChineseVariant::Traditional, 96);
+    test!("一無量大數", big_unsigned_integer_to_chinese_middle, ChineseVariant::Traditional, 128);
+    test!(
+        "一千萬無量大數",
+        big_unsigned_integer_to_chinese_middle,
+        ChineseVariant::Traditional,
+        135
+    );
+}
+
+#[test]
+fn test_positive_float_to_chinese_decimal_unit() {
+    macro_rules! test {
+        ($expect:expr, $chinese_variant:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                positive_float_to_chinese_decimal_unit(
+                    $chinese_variant,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand,
+                    $value
+                )
+                .unwrap()
+            );
+        };
+    }
+
+    test!("零", ChineseVariant::Traditional, 0.0);
+    test!("三分五釐", ChineseVariant::Traditional, 0.35);
+    test!("三分五厘", ChineseVariant::Simple, 0.35);
+    test!("二毫", ChineseVariant::Traditional, 0.002);
+    test!("一零五釐", ChineseVariant::Traditional, 1.05);
+    test!("一百零五釐", ChineseVariant::Traditional, 100.05);
+    test!("一分零三毫", ChineseVariant::Traditional, 0.103);
+    test!("五絲", ChineseVariant::Traditional, 0.0005);
+    test!("五丝", ChineseVariant::Simple, 0.0005);
+    test!("一纖二沙", ChineseVariant::Traditional, 0.000_000_12);
+    test!("一纤二沙", ChineseVariant::Simple, 0.000_000_12);
+    test!("二", ChineseVariant::Traditional, 1.999_999_999);
+}
+
+#[test]
+fn test_f32_to_f64_shortest() {
+    assert_eq!(0.35, f32_to_f64_shortest(0.35));
+    assert_eq!(0.000_000_12, f32_to_f64_shortest(0.000_000_12));
+    assert_eq!(-123.456, f32_to_f64_shortest(-123.456));
+    assert_eq!(99999.99, f32_to_f64_shortest(99999.99));
+    assert_eq!(16777216.0, f32_to_f64_shortest(16777216.0));
+}
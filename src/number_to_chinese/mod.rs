@@ -1,18 +1,35 @@
+mod arabic_text;
 mod functions;
 mod naive;
 mod number_to_chinese_error;
+mod quantity;
 mod traits;
 
 mod functions_test;
 
 use alloc::string::String;
 
+#[cfg(feature = "chinese-to-number")]
+use num_traits::NumCast;
+
+pub use arabic_text::*;
 use functions::*;
+#[cfg(feature = "chinese-to-number")]
+pub(crate) use functions::{
+    big_integer_to_chinese_by_method, prepend_negative_sign, push_decimal_unit_fraction,
+    push_money_fraction,
+};
 pub use naive::*;
 pub use number_to_chinese_error::*;
+pub use quantity::*;
 pub use traits::*;
 
-use crate::{ChineseCase, ChineseCountMethod, ChineseVariant};
+use crate::{ChineseCase, ChineseCountMethod, ChineseExponent, ChineseLargestUnit, ChineseVariant};
+#[cfg(feature = "chinese-to-number")]
+use crate::{
+    ChineseRange, ChineseRangeSeparator,
+    chinese_range::{read_range_low, shared_range_low, split_integral},
+};
 
 // TODO unsigned integer
 
@@ -76,7 +93,17 @@ pub fn from_u32_to_chinese_high(
     from_u128_to_chinese_high(chinese_variant, chinese_case, value as u128)
 }
 
-/// 將 `u64` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `u32` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_u32_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: u32,
+) -> String {
+    from_u128_to_chinese_mainland(chinese_variant, chinese_case, value as u128)
+}
+
+/// 將 `u64` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_u64_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -116,14 +143,24 @@ pub fn from_u64_to_chinese_high(
     from_u128_to_chinese_high(chinese_variant, chinese_case, value as u128)
 }
 
-/// 將 `u128` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `u64` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_u64_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: u64,
+) -> String {
+    from_u128_to_chinese_mainland(chinese_variant, chinese_case, value as u128)
+}
+
+/// 將 `u128` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_u128_to_chinese_low(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: u128,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1_0000_0000_0000_0000 {
+    if value >= 10_0000_0000_0000_0000_0000 {
         return Err(NumberToChineseError::Overflow);
     }
 
@@ -160,7 +197,23 @@ pub fn from_u128_to_chinese_high(
     unsigned_integer_to_chinese_high(chinese_variant, chinese_case, false, value)
 }
 
-/// 將 `usize` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `u128` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_u128_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: u128,
+) -> String {
+    unsigned_integer_to_chinese_compound(
+        chinese_variant,
+        chinese_case,
+        false,
+        value,
+        ChineseExponent::億,
+    )
+}
+
+/// 將 `usize` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_usize_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -200,6 +253,16 @@ pub fn from_usize_to_chinese_high(
     from_u128_to_chinese_high(chinese_variant, chinese_case, value as u128)
 }
 
+/// 將 `usize` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_usize_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: usize,
+) -> String {
+    from_u128_to_chinese_mainland(chinese_variant, chinese_case, value as u128)
+}
+
 // TODO signed integer
 
 /// 將 `i8` 整數轉成中文數字。
@@ -262,7 +325,17 @@ pub fn from_i32_to_chinese_high(
     from_i128_to_chinese_high(chinese_variant, chinese_case, value as i128)
 }
 
-/// 將 `i64` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `i32` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_i32_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: i32,
+) -> String {
+    from_i128_to_chinese_mainland(chinese_variant, chinese_case, value as i128)
+}
+
+/// 將 `i64` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_i64_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -302,7 +375,17 @@ pub fn from_i64_to_chinese_high(
     from_i128_to_chinese_high(chinese_variant, chinese_case, value as i128)
 }
 
-/// 將 `i128` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `i64` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_i64_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: i64,
+) -> String {
+    from_i128_to_chinese_mainland(chinese_variant, chinese_case, value as i128)
+}
+
+/// 將 `i128` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_i128_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -383,7 +466,27 @@ pub fn from_i128_to_chinese_high(
     }
 }
 
-/// 將 `isize` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `i128` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_i128_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: i128,
+) -> String {
+    let (negative, magnitude) = split_i128_sign(value);
+
+    if negative {
+        let mut s = from_u128_to_chinese_mainland(chinese_variant, chinese_case, magnitude);
+
+        prepend_negative_sign(chinese_variant, &mut s);
+
+        s
+    } else {
+        from_u128_to_chinese_mainland(chinese_variant, chinese_case, magnitude)
+    }
+}
+
+/// 將 `isize` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_isize_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -423,9 +526,19 @@ pub fn from_isize_to_chinese_high(
     from_i128_to_chinese_high(chinese_variant, chinese_case, value as i128)
 }
 
+/// 將 `isize` 整數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_isize_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: isize,
+) -> String {
+    from_i128_to_chinese_mainland(chinese_variant, chinese_case, value as i128)
+}
+
 // TODO float
 
-/// 將 `f32` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `f32` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_f32_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -465,6 +578,16 @@ pub fn from_f32_to_chinese_high(
     from_f64_to_chinese_high(chinese_variant, chinese_case, value as f64)
 }
 
+/// 將 `f32` 浮點數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_f32_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: f32,
+) -> Result<String, NumberToChineseError> {
+    from_f64_to_chinese_mainland(chinese_variant, chinese_case, value as f64)
+}
+
 #[inline]
 fn from_f64_to_chinese(
     chinese_variant: ChineseVariant,
@@ -477,7 +600,7 @@ fn from_f64_to_chinese(
     })
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
 #[inline]
 pub fn from_f64_to_chinese_low(
     chinese_variant: ChineseVariant,
@@ -487,7 +610,7 @@ pub fn from_f64_to_chinese_low(
     from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Low, value)
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「萬進」**。數值的絕對值不能大於或等於 `1e52`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「萬進」**。數值的絕對值不能大於或等於 `1e72`。
 #[inline]
 pub fn from_f64_to_chinese_ten_thousand(
     chinese_variant: ChineseVariant,
@@ -497,7 +620,7 @@ pub fn from_f64_to_chinese_ten_thousand(
     from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::TenThousand, value)
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「中數」**。數值的絕對值不能大於或等於 `1e96`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「中數」**。數值的絕對值不能大於或等於 `1e136`。
 #[inline]
 pub fn from_f64_to_chinese_middle(
     chinese_variant: ChineseVariant,
@@ -516,3 +639,206 @@ pub fn from_f64_to_chinese_high(
 ) -> Result<String, NumberToChineseError> {
     from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::High, value)
 }
+
+/// 將 `f64` 浮點數轉成中文數字，使用 **「大陸用法」**。
+#[inline]
+pub fn from_f64_to_chinese_mainland(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Mainland, value)
+}
+
+/// 將 `f32` 浮點數轉成中文數字，小數部份使用 **「分、釐、毫、絲、忽、微、纖、沙」** 等小數單位，而非金額的「角、分」。數值會先以 `f32` 的精度取最短的十進位寫法，例如 `0.35f32` 會被當作 `0.35`。
+#[inline]
+pub fn from_f32_to_chinese_decimal_unit(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f32,
+) -> Result<String, NumberToChineseError> {
+    from_f64_to_chinese_decimal_unit(
+        chinese_variant,
+        chinese_case,
+        method,
+        f32_to_f64_shortest(value),
+    )
+}
+
+/// 將 `f64` 浮點數轉成中文數字，小數部份使用 **「分、釐、毫、絲、忽、微、纖、沙」** 等小數單位，而非金額的「角、分」。小數部份會被四捨五入到「沙」。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_f64_to_chinese_decimal_unit, ChineseCase, ChineseCountMethod, ChineseVariant,
+/// };
+///
+/// assert_eq!(
+///     "三分五釐",
+///     from_f64_to_chinese_decimal_unit(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         0.35
+///     )
+///     .unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_f64_to_chinese_decimal_unit(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    signed_f64_to_chinese(chinese_variant, value, |value| {
+        positive_float_to_chinese_decimal_unit(chinese_variant, chinese_case, method, value)
+    })
+}
+
+/// 將 `u128` 整數轉成中文數字，使用 **「萬進」**，但不使用比 `largest_unit` 更大的單位，而是以 `largest_unit` 組合。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_u128_to_chinese_compound, ChineseCase, ChineseLargestUnit, ChineseVariant,
+/// };
+///
+/// assert_eq!(
+///     "一萬億",
+///     from_u128_to_chinese_compound(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseLargestUnit::億,
+///         1_0000_0000_0000
+///     )
+/// );
+/// assert_eq!(
+///     "三億五千萬億",
+///     from_u128_to_chinese_compound(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseLargestUnit::億,
+///         3_5000_0000_0000_0000
+///     )
+/// );
+/// ```
+#[inline]
+pub fn from_u128_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    largest_unit: ChineseLargestUnit,
+    value: u128,
+) -> String {
+    unsigned_integer_to_chinese_compound(
+        chinese_variant,
+        chinese_case,
+        false,
+        value,
+        largest_unit.to_exponent(),
+    )
+}
+
+/// 將 `i128` 整數轉成中文數字，使用 **「萬進」**，但不使用比 `largest_unit` 更大的單位，而是以 `largest_unit` 組合。
+#[inline]
+pub fn from_i128_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    largest_unit: ChineseLargestUnit,
+    value: i128,
+) -> String {
+    let (negative, magnitude) = split_i128_sign(value);
+
+    let mut s =
+        from_u128_to_chinese_compound(chinese_variant, chinese_case, largest_unit, magnitude);
+
+    if negative {
+        prepend_negative_sign(chinese_variant, &mut s);
+    }
+
+    s
+}
+
+/// 將 `f64` 浮點數轉成中文數字，使用 **「萬進」**，但不使用比 `largest_unit` 更大的單位，而是以 `largest_unit` 組合。
+#[inline]
+pub fn from_f64_to_chinese_compound(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    largest_unit: ChineseLargestUnit,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    signed_f64_to_chinese(chinese_variant, value, |value| {
+        positive_float_to_chinese_compound(
+            chinese_variant,
+            chinese_case,
+            largest_unit.to_exponent(),
+            value,
+        )
+    })
+}
+
+/// 將數值範圍轉成中文數字。如果上下限的單位相同，則只在上限寫出單位，例如「三至五萬」，但只有在 `from_chinese_to_range` 能讀回相同的下限時才會省略。
+///
+/// 若下限即使寫出完整的數字，仍會被當作套用了上限的單位，例如 `(3, 50000)` 寫成的「三至五萬」，則回傳 `NumberToChineseError::AmbiguousRange`。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_range_to_chinese, ChineseCase, ChineseCountMethod, ChineseRange, ChineseRangeSeparator,
+///     ChineseVariant, NumberToChineseError,
+/// };
+///
+/// assert_eq!(
+///     "三至五萬",
+///     from_range_to_chinese(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         ChineseRangeSeparator::To,
+///         ChineseRange::new(30000, 50000)
+///     )
+///     .unwrap()
+/// );
+/// assert_eq!(
+///     Err(NumberToChineseError::AmbiguousRange),
+///     from_range_to_chinese(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         ChineseRangeSeparator::To,
+///         ChineseRange::new(3, 50000)
+///     )
+/// );
+/// ```
+#[cfg(feature = "chinese-to-number")]
+pub fn from_range_to_chinese<T: NumberToChinese + NumCast + PartialOrd + Copy>(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    separator: ChineseRangeSeparator,
+    range: ChineseRange<T>,
+) -> Result<String, NumberToChineseError> {
+    let low = range.low.to_chinese(chinese_variant, chinese_case, method)?;
+    let high = range.high.to_chinese(chinese_variant, chinese_case, method)?;
+
+    if let (Some((false, low)), Some((false, high_magnitude))) =
+        (split_integral(range.low), split_integral(range.high))
+        && let Some(shared_low) = shared_range_low(method, low, high_magnitude)
+        && let Some(written_low) = T::from(shared_low)
+        && read_range_low(method, written_low, range.high) == range.low
+    {
+        let shared_low = shared_low.to_chinese(chinese_variant, chinese_case, method)?;
+
+        return Ok(join_range_chinese(separator, &shared_low, &high));
+    }
+
+    if read_range_low(method, range.low, range.high) == range.low {
+        Ok(join_range_chinese(separator, &low, &high))
+    } else {
+        Err(NumberToChineseError::AmbiguousRange)
+    }
+}
@@ -1,13 +1,57 @@
+use alloc::string::{String, ToString};
 use core::{
     error::Error,
     fmt::{self, Display, Formatter},
 };
 
+use crate::ChineseVariant;
+
 /// 將數值轉成中文數字時發生的錯誤。
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum NumberToChineseError {
     Overflow,
     Underflow,
+    /// 數值範圍寫成中文數字後會被當作另一個範圍，例如 `(3, 50000)` 寫成的「三至五萬」會被讀成三萬至五萬。
+    AmbiguousRange,
+}
+
+impl NumberToChineseError {
+    /// 取得以中文描述錯誤的 `Display` 實作。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseVariant, NumberToChineseError};
+    ///
+    /// assert_eq!(
+    ///     "數值太大",
+    ///     NumberToChineseError::Overflow.localized(ChineseVariant::Traditional).to_string()
+    /// );
+    /// ```
+    #[inline]
+    pub const fn localized(self, chinese_variant: ChineseVariant) -> LocalizedNumberToChineseError {
+        LocalizedNumberToChineseError {
+            error: self,
+            chinese_variant,
+        }
+    }
+
+    /// 將錯誤以中文描述。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseVariant, NumberToChineseError};
+    ///
+    /// assert_eq!(
+    ///     "数值太小",
+    ///     NumberToChineseError::Underflow.to_localized_string(ChineseVariant::Simple)
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_localized_string(self, chinese_variant: ChineseVariant) -> String {
+        self.localized(chinese_variant).to_string()
+    }
 }
 
 impl Display for NumberToChineseError {
@@ -16,8 +60,38 @@ impl Display for NumberToChineseError {
         match self {
             NumberToChineseError::Overflow => f.write_str("number is too large"),
             NumberToChineseError::Underflow => f.write_str("number is too small"),
+            NumberToChineseError::AmbiguousRange => {
+                f.write_str("the range would be read as another range")
+            },
         }
     }
 }
 
 impl Error for NumberToChineseError {}
+
+/// 以中文描述 `NumberToChineseError` 的 `Display` 實作。
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LocalizedNumberToChineseError {
+    error:           NumberToChineseError,
+    chinese_variant: ChineseVariant,
+}
+
+impl Display for LocalizedNumberToChineseError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let s = match (self.error, self.chinese_variant) {
+            (NumberToChineseError::Overflow, ChineseVariant::Traditional) => "數值太大",
+            (NumberToChineseError::Overflow, ChineseVariant::Simple) => "数值太大",
+            (NumberToChineseError::Underflow, ChineseVariant::Traditional) => "數值太小",
+            (NumberToChineseError::Underflow, ChineseVariant::Simple) => "数值太小",
+            (NumberToChineseError::AmbiguousRange, ChineseVariant::Traditional) => {
+                "範圍會被讀成另一個範圍"
+            },
+            (NumberToChineseError::AmbiguousRange, ChineseVariant::Simple) => {
+                "范围会被读成另一个范围"
+            },
+        };
+
+        f.write_str(s)
+    }
+}
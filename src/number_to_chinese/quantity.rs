@@ -0,0 +1,307 @@
+use alloc::string::String;
+
+use super::{NumberToChinese, join_range_chinese};
+use crate::{
+    ChineseApproximateNumber, ChineseCase, ChineseCountMethod, ChineseRangeSeparator,
+    ChineseVariant, NumberToChineseError,
+    chinese_characters::{ChineseApproximation, ChineseExponent, ChineseNumber},
+    chinese_range::shared_range_low,
+};
+
+#[inline]
+fn is_ten_position(method: ChineseCountMethod, position: u32) -> bool {
+    match method {
+        ChineseCountMethod::Low => position == 1,
+        _ => position % 4 == 1,
+    }
+}
+
+fn quantity_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: u128,
+    counted: bool,
+) -> Result<String, NumberToChineseError> {
+    let s = value.to_chinese(chinese_variant, chinese_case, method)?;
+
+    let position = value.checked_ilog10().unwrap_or(0);
+
+    if chinese_case != ChineseCase::Lower
+        || value / 10u128.pow(position) != 2
+        || is_ten_position(method, position)
+        || (position == 0 && !counted)
+    {
+        return Ok(s);
+    }
+
+    let two = ChineseNumber::二;
+
+    let mut t = String::from(two.to_measure_word_str(chinese_variant, chinese_case));
+
+    t.push_str(&s[two.to_str(chinese_variant, chinese_case).len()..]);
+
+    Ok(t)
+}
+
+fn approximate_digits_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    min: u128,
+    max: u128,
+) -> Result<Option<String>, NumberToChineseError> {
+    if min == 0 || min >= max {
+        return Ok(None);
+    }
+
+    let mut position = 0;
+    let mut unit = 1;
+
+    while (min / unit).is_multiple_of(10) {
+        position += 1;
+        unit *= 10;
+    }
+
+    let higher = min / unit / 10;
+
+    if !max.is_multiple_of(unit) || max / unit / 10 != higher {
+        return Ok(None);
+    }
+
+    let min_digit = (min / unit % 10) as u8;
+    let max_digit = (max / unit % 10) as u8;
+
+    let leading = higher == 0;
+
+    // 一 is not written before a leading 十, such as in "十萬".
+    if min_digit == 1 && leading && is_ten_position(method, position) {
+        return Ok(None);
+    }
+
+    let mut digits = String::new();
+
+    match (min_digit, max_digit) {
+        _ if max_digit == min_digit + 1 => {
+            // Both digits count what follows, so "兩三個" and "兩三百個" use 兩, but "二三十個" does not.
+            let counted = leading && !is_ten_position(method, position);
+
+            for digit in [min_digit, max_digit] {
+                let digit = unsafe { ChineseNumber::from_ordinal_unsafe(digit) };
+
+                digits.push_str(if counted && chinese_case == ChineseCase::Lower {
+                    digit.to_measure_word_str(chinese_variant, chinese_case)
+                } else {
+                    digit.to_str(chinese_variant, chinese_case)
+                });
+            }
+        },
+        // The same rule as the parser, 幾 is at least two in the leading position.
+        (2, 9) if leading => digits.push_str(ChineseApproximation::幾.to_str(chinese_variant)),
+        (1, 9) if !leading => digits.push_str(ChineseApproximation::幾.to_str(chinese_variant)),
+        _ => return Ok(None),
+    }
+
+    let s = if leading {
+        min.to_chinese(chinese_variant, chinese_case, method)?
+    } else {
+        quantity_to_chinese(chinese_variant, chinese_case, method, min, true)?
+    };
+
+    // The digit is the last one written, only units follow it.
+    let min_digit = unsafe { ChineseNumber::from_ordinal_unsafe(min_digit) }
+        .to_str(chinese_variant, chinese_case);
+
+    let Some(index) = s.rfind(min_digit) else {
+        return Ok(None);
+    };
+
+    let mut t = String::with_capacity(s.len() + digits.len());
+
+    t.push_str(&s[..index]);
+    t.push_str(&digits);
+    t.push_str(&s[index + min_digit.len()..]);
+
+    Ok(Some(t))
+}
+
+#[inline]
+fn is_power_of_ten(n: u128) -> bool {
+    n > 0 && 10u128.pow(n.ilog10()) == n
+}
+
+fn approximate_number_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    number: ChineseApproximateNumber,
+) -> Result<String, NumberToChineseError> {
+    let ChineseApproximateNumber {
+        min,
+        max,
+        approximate,
+    } = number;
+
+    if !approximate || min == max {
+        return quantity_to_chinese(chinese_variant, chinese_case, method, min, true);
+    }
+
+    if min < max {
+        if let Some(s) =
+            approximate_digits_to_chinese(chinese_variant, chinese_case, method, min, max)?
+        {
+            return Ok(s);
+        }
+
+        // "一百多" covers 101 to 199, the amount after 多 is less than one of the last unit.
+        let base = min - 1;
+        let step = max - base + 1;
+
+        if base > 0
+            && step >= 10
+            && is_power_of_ten(step)
+            && base.is_multiple_of(step)
+            && !(base / step).is_multiple_of(10)
+        {
+            let mut s = quantity_to_chinese(chinese_variant, chinese_case, method, base, true)?;
+
+            s.push_str(ChineseApproximation::多.to_str(chinese_variant));
+
+            return Ok(s);
+        }
+
+        // "上百" covers 100 to 999, but there is no "上十萬".
+        if min >= 100
+            && is_power_of_ten(min)
+            && !is_ten_position(method, min.ilog10())
+            && min.checked_mul(10) == max.checked_add(1)
+        {
+            let min_s = min.to_chinese(chinese_variant, chinese_case, method)?;
+            let one = ChineseNumber::一.to_str(chinese_variant, chinese_case);
+
+            let mut s = String::from(ChineseApproximation::上.to_str(chinese_variant));
+
+            s.push_str(&min_s[one.len()..]);
+
+            return Ok(s);
+        }
+    }
+
+    let min_s = quantity_to_chinese(
+        chinese_variant,
+        chinese_case,
+        method,
+        shared_range_low(method, min, max).unwrap_or(min),
+        false,
+    )?;
+    let max_s = max.to_chinese(chinese_variant, chinese_case, method)?;
+
+    Ok(join_range_chinese(ChineseRangeSeparator::To, &min_s, &max_s))
+}
+
+fn push_measure_word(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    s: &mut String,
+    measure_word: &str,
+) {
+    match measure_word {
+        "個" | "个" => s.push_str(ChineseExponent::個.to_str(chinese_variant, chinese_case)),
+        _ => s.push_str(measure_word),
+    }
+}
+
+/// 將 `u128` 整數加上量詞轉成中文數量，例如「兩個」、「十二個」和「兩千本」。量詞為「個」或「个」時，會依照中文變體轉換。序數（例如「第二個」）不適用此函數。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_u128_to_chinese_quantity, ChineseCase, ChineseCountMethod, ChineseVariant,
+/// };
+///
+/// assert_eq!(
+///     "兩個",
+///     from_u128_to_chinese_quantity(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         2,
+///         "個"
+///     )
+///     .unwrap()
+/// );
+/// assert_eq!(
+///     "二十二个",
+///     from_u128_to_chinese_quantity(
+///         ChineseVariant::Simple,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         22,
+///         "個"
+///     )
+///     .unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_u128_to_chinese_quantity(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: u128,
+    measure_word: &str,
+) -> Result<String, NumberToChineseError> {
+    let mut s = quantity_to_chinese(chinese_variant, chinese_case, method, value, true)?;
+
+    push_measure_word(chinese_variant, chinese_case, &mut s, measure_word);
+
+    Ok(s)
+}
+
+/// 將約數加上量詞轉成中文數量，例如「三四個」、「十幾個」、「上百個」和「一百多個」。無法以約數的寫法來表示時，會寫成範圍，例如「五至十二個」。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     from_approximate_number_to_chinese_quantity, ChineseApproximateNumber, ChineseCase,
+///     ChineseCountMethod, ChineseVariant,
+/// };
+///
+/// assert_eq!(
+///     "兩三個",
+///     from_approximate_number_to_chinese_quantity(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         ChineseApproximateNumber::approximate(2, 3),
+///         "個"
+///     )
+///     .unwrap()
+/// );
+/// assert_eq!(
+///     "十幾個",
+///     from_approximate_number_to_chinese_quantity(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///         ChineseApproximateNumber::approximate(11, 19),
+///         "個"
+///     )
+///     .unwrap()
+/// );
+/// ```
+#[inline]
+pub fn from_approximate_number_to_chinese_quantity(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    number: ChineseApproximateNumber,
+    measure_word: &str,
+) -> Result<String, NumberToChineseError> {
+    let mut s = approximate_number_to_chinese(chinese_variant, chinese_case, method, number)?;
+
+    push_measure_word(chinese_variant, chinese_case, &mut s, measure_word);
+
+    Ok(s)
+}
@@ -4,9 +4,9 @@ use super::*;
 pub trait NumberToChinese {
     /// 將數值轉成中文數字。
     ///
-    /// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
-    /// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e52`。
-    /// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e96`。
+    /// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `10_0000_0000_0000_0000_0000`。
+    /// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e72`。
+    /// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e136`。
     ///
     /// ## 範例
     ///
@@ -234,6 +234,9 @@ impl NumberToChinese for u128 {
             ChineseCountMethod::High => {
                 Ok(from_u128_to_chinese_high(chinese_variant, chinese_case, self))
             },
+            ChineseCountMethod::Mainland => {
+                Ok(from_u128_to_chinese_mainland(chinese_variant, chinese_case, self))
+            },
         }
     }
 
@@ -268,6 +271,9 @@ impl NumberToChinese for i128 {
             ChineseCountMethod::High => {
                 Ok(from_i128_to_chinese_high(chinese_variant, chinese_case, self))
             },
+            ChineseCountMethod::Mainland => {
+                Ok(from_i128_to_chinese_mainland(chinese_variant, chinese_case, self))
+            },
         }
     }
 
@@ -363,6 +369,9 @@ impl NumberToChinese for f64 {
             ChineseCountMethod::High => {
                 from_f64_to_chinese_high(chinese_variant, chinese_case, self)
             },
+            ChineseCountMethod::Mainland => {
+                from_f64_to_chinese_mainland(chinese_variant, chinese_case, self)
+            },
         }
     }
 
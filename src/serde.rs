@@ -0,0 +1,270 @@
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+use ::serde::{
+    Deserializer, Serializer,
+    de::{self, Unexpected, Visitor},
+    ser,
+};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use num_traits::float::FloatCore;
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{ChineseCase, ChineseCountMethod, ChineseToNumber, ChineseVariant, NumberToChinese};
+
+macro_rules! serde_module {
+    ($name:ident, $chinese_variant:ident, $chinese_case:ident, $method:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// 搭配 `#[serde(with = "...")]` 使用。序列化時會寫成中文數字，反序列化時只接受中文數字，若也要接受數值請使用 `number_or_chinese` 模組。
+        pub mod $name {
+            use ::serde::{Deserializer, Serializer};
+            use num_traits::{NumCast, ToPrimitive};
+
+            use crate::{
+                ChineseCase, ChineseCountMethod, ChineseToNumber, ChineseVariant, NumberToChinese,
+            };
+
+            /// 將數值寫成中文數字。
+            #[inline]
+            pub fn serialize<T: NumberToChinese + Copy, S: Serializer>(
+                value: &T,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                crate::serde::serialize(
+                    *value,
+                    ChineseVariant::$chinese_variant,
+                    ChineseCase::$chinese_case,
+                    ChineseCountMethod::$method,
+                    serializer,
+                )
+            }
+
+            /// 將中文數字轉成數值。
+            #[inline]
+            pub fn deserialize<'de, T: NumCast + ToPrimitive, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<T, D::Error>
+            where
+                for<'a> &'a str: ChineseToNumber<T>, {
+                crate::serde::deserialize(ChineseCountMethod::$method, false, deserializer)
+            }
+
+            /// 序列化的方式和上層模組相同，但反序列化時也接受數值，例如 JSON 中的 `1200`。反序列化的格式必須能夠自我描述。
+            pub mod number_or_chinese {
+                use super::*;
+
+                /// 將數值寫成中文數字。
+                #[inline]
+                pub fn serialize<T: NumberToChinese + Copy, S: Serializer>(
+                    value: &T,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize(value, serializer)
+                }
+
+                /// 將中文數字或數值轉成數值。
+                #[inline]
+                pub fn deserialize<'de, T: NumCast + ToPrimitive, D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<T, D::Error>
+                where
+                    for<'a> &'a str: ChineseToNumber<T>, {
+                    crate::serde::deserialize(ChineseCountMethod::$method, true, deserializer)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! serde_modules {
+    ($chinese_variant:ident, $variant_name:expr) => {
+        serde_module!(
+            low_upper,
+            $chinese_variant,
+            Upper,
+            Low,
+            concat!("使用", $variant_name, "大寫數字和 **「下數」**。")
+        );
+        serde_module!(
+            low_lower,
+            $chinese_variant,
+            Lower,
+            Low,
+            concat!("使用", $variant_name, "小寫數字和 **「下數」**。")
+        );
+        serde_module!(
+            ten_thousand_upper,
+            $chinese_variant,
+            Upper,
+            TenThousand,
+            concat!("使用", $variant_name, "大寫數字和 **「萬進」**。")
+        );
+        serde_module!(
+            ten_thousand_lower,
+            $chinese_variant,
+            Lower,
+            TenThousand,
+            concat!("使用", $variant_name, "小寫數字和 **「萬進」**。")
+        );
+        serde_module!(
+            middle_upper,
+            $chinese_variant,
+            Upper,
+            Middle,
+            concat!("使用", $variant_name, "大寫數字和 **「中數」**。")
+        );
+        serde_module!(
+            middle_lower,
+            $chinese_variant,
+            Lower,
+            Middle,
+            concat!("使用", $variant_name, "小寫數字和 **「中數」**。")
+        );
+        serde_module!(
+            high_upper,
+            $chinese_variant,
+            Upper,
+            High,
+            concat!("使用", $variant_name, "大寫數字和 **「上數」**。")
+        );
+        serde_module!(
+            high_lower,
+            $chinese_variant,
+            Lower,
+            High,
+            concat!("使用", $variant_name, "小寫數字和 **「上數」**。")
+        );
+        serde_module!(
+            mainland_upper,
+            $chinese_variant,
+            Upper,
+            Mainland,
+            concat!("使用", $variant_name, "大寫數字和 **「大陸用法」**。")
+        );
+        serde_module!(
+            mainland_lower,
+            $chinese_variant,
+            Lower,
+            Mainland,
+            concat!("使用", $variant_name, "小寫數字和 **「大陸用法」**。")
+        );
+    };
+}
+
+/// 輸出繁體中文數字的模組。
+pub mod traditional {
+    serde_modules!(Traditional, "繁體");
+}
+
+/// 輸出簡體中文數字的模組。
+pub mod simple {
+    serde_modules!(Simple, "簡體");
+}
+
+pub use traditional::*;
+
+fn serialize<T: NumberToChinese, S: Serializer>(
+    value: T,
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let chinese =
+        value.to_chinese(chinese_variant, chinese_case, method).map_err(ser::Error::custom)?;
+
+    serializer.serialize_str(&chinese)
+}
+
+fn deserialize<'de, T: NumCast + ToPrimitive, D: Deserializer<'de>>(
+    method: ChineseCountMethod,
+    accept_number: bool,
+    deserializer: D,
+) -> Result<T, D::Error>
+where
+    for<'a> &'a str: ChineseToNumber<T>, {
+    let visitor = ChineseNumberVisitor {
+        method,
+        accept_number,
+        _phantom: PhantomData,
+    };
+
+    if accept_number {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+struct ChineseNumberVisitor<T> {
+    method:        ChineseCountMethod,
+    accept_number: bool,
+    _phantom:      PhantomData<T>,
+}
+
+impl<T: NumCast + ToPrimitive> ChineseNumberVisitor<T>
+where
+    for<'a> &'a str: ChineseToNumber<T>,
+{
+    fn number<E: de::Error>(&self, n: Option<T>, unexpected: Unexpected) -> Result<T, E> {
+        if !self.accept_number {
+            return Err(E::invalid_type(unexpected, self));
+        }
+
+        n.ok_or_else(|| E::invalid_value(unexpected, self))
+    }
+}
+
+impl<T: NumCast + ToPrimitive> Visitor<'_> for ChineseNumberVisitor<T>
+where
+    for<'a> &'a str: ChineseToNumber<T>,
+{
+    type Value = T;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        if self.accept_number {
+            f.write_str("a chinese number or a number")
+        } else {
+            f.write_str("a chinese number")
+        }
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.to_number(self.method).map_err(E::custom)
+    }
+
+    #[inline]
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.number(T::from(v), Unexpected::Signed(v))
+    }
+
+    #[inline]
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<T, E> {
+        self.number(T::from(v), Unexpected::Other("i128"))
+    }
+
+    #[inline]
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.number(T::from(v), Unexpected::Unsigned(v))
+    }
+
+    #[inline]
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<T, E> {
+        self.number(T::from(v), Unexpected::Other("u128"))
+    }
+
+    #[inline]
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        // `NumCast` drops the fraction when `T` is an integer, which should be an error instead.
+        let n = T::from(v)
+            .filter(|n| v.fract() == 0.0 || n.to_f64().is_some_and(|n| n.fract() != 0.0));
+
+        self.number(n, Unexpected::Float(v))
+    }
+}
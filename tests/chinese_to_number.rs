@@ -1,16 +1,28 @@
 #![cfg(feature = "chinese-to-number")]
 
 use assert_eq_float::assert_eq_float;
-use chinese_number::{ChineseCountMethod, ChineseToNumber, ChineseToNumberError};
+use chinese_number::{
+    ChineseApproximateNumber, ChineseCase, ChineseCountMethod, ChineseCountMethodDetector,
+    ChineseDetected, ChineseExponent, ChineseLargestUnit, ChineseNumberAst, ChineseNumberExpected,
+    ChineseNumberLexer, ChineseNumberMatch, ChineseNumberPosition, ChineseNumberScanner,
+    ChineseNumberToken, ChineseNumberTokenKind, ChineseParseOptions, ChineseRange, ChineseSign,
+    ChineseToArabicOptions, ChineseToNumber, ChineseToNumberError, ChineseVariant,
+    detect_count_method, detect_number_style, from_chinese_text_to_arabic,
+    from_chinese_to_approximate_number, from_chinese_to_ast, from_chinese_to_f64_compound,
+    from_chinese_to_i128_compound, from_chinese_to_interpretations, from_chinese_to_range,
+    from_chinese_to_u128_compound, scan_chinese_numbers,
+};
 
 macro_rules! test_group {
     ($method:expr) => {
+        #[allow(unused)]
         macro_rules! test {
             ($expect: expr,$value: expr) => {
                 assert_eq!($expect, $value.to_number($method).unwrap());
             };
         }
 
+        #[allow(unused)]
         macro_rules! test_float {
             ($expect: expr,$value: expr) => {
                 assert_eq_float!($expect, $value.to_number($method).unwrap());
@@ -21,6 +33,7 @@ macro_rules! test_group {
             };
         }
 
+        #[allow(unused)]
         macro_rules! test_err {
             ($expect: expr,$value: expr) => {
                 test_err!(i8, $expect, $value);
@@ -118,14 +131,22 @@ fn to_number_low() {
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 0
+        ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('a'),
+            },
         },
         "a"
     );
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 1
+        ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('佰'),
+            },
         },
         "壹佰貳拾佰"
     );
@@ -158,18 +179,27 @@ fn to_number_ten_thousand() {
     test_float!(-123.46f32, "負壹佰貳拾參肆角陸分");
     test_float!(123.46f64, "壹佰貳拾參肆角陸分");
     test_float!(-123.46f64, "負壹佰貳拾參肆角陸分");
+    test_float!(-0.5f64, "負伍角");
 
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 0
+        ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('a'),
+            },
         },
         "a"
     );
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 1
+        ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('佰'),
+            },
         },
         "壹佰貳拾佰"
     );
@@ -211,14 +241,22 @@ fn to_number_middle() {
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 0
+        ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('a'),
+            },
         },
         "a"
     );
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 1
+        ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('佰'),
+            },
         },
         "壹佰貳拾佰"
     );
@@ -270,14 +308,22 @@ fn to_number_high() {
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 0
+        ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('a'),
+            },
         },
         "a"
     );
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 1
+        ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('佰'),
+            },
         },
         "壹佰貳拾佰"
     );
@@ -286,6 +332,156 @@ fn to_number_high() {
     test_err!(i8, ChineseToNumberError::Underflow, "負壹佰貳拾玖");
 }
 
+#[test]
+fn to_number_mainland() {
+    test_group!(ChineseCountMethod::Mainland);
+
+    test!(i8::MAX, "一百二十七");
+    test!(u32::MAX, "四十二億九千四百九十六萬七千二百九十五");
+    test!(u64::MAX, "一千八百四十四億六千七百四十四萬零七百三十七億零九百五十五萬一千六百一十五");
+    test!(i64::MIN, "負九百二十二億三千三百七十二萬零三百六十八億五千四百七十七萬五千八百零八");
+
+    test!(100_0000u32, "一兆");
+    test!(350_0000u32, "三兆五");
+    test!(5000_0000u32, "五十兆");
+    test!(3_0500_0000u32, "三億五兆");
+    test!(1_0000_0000_0000u64, "一萬億");
+    test!(12_0000_0000_0003u64, "十二萬億零三");
+    test!(1_0000_0000_0000_0000u64, "一億億");
+    test!(3_5000_0000_0000_0000u64, "三億五千萬億");
+    test!(1_0000_0000_0000_0000_0000u128, "一萬億億");
+    test!(1_0000_0000_0000_0000u64, "一京");
+
+    test_float!(100_0000f64, "一兆");
+    test_float!(1e16f64, "一億億");
+
+    test_err!(
+        ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('兆'),
+            },
+        },
+        "一兆二兆"
+    );
+
+    test_err!(u32, ChineseToNumberError::Overflow, "一萬億");
+}
+
+#[test]
+fn to_number_large_units() {
+    {
+        test_group!(ChineseCountMethod::TenThousand);
+
+        test_float!(1e52f64, "壹恆河沙", 1e37);
+        test_float!(3e56f64, "參阿僧祇", 1e41);
+        test_float!(1.2e68f64, "一無量大數二千不可思議", 1e53);
+        test_float!(1.2e68f64, "一无量大数二千不可思议", 1e53);
+
+        test_err!(u128, ChineseToNumberError::Overflow, "一恆河沙");
+    }
+
+    {
+        test_group!(ChineseCountMethod::Middle);
+
+        test_float!(1e96f64, "一恆河沙", 1e81);
+        test_float!(5e128f64, "五無量大數", 1e114);
+    }
+
+    {
+        test_group!(ChineseCountMethod::Low);
+
+        test!(1_0000_0000_0000_0000u128, "一恆河沙");
+        test!(9_0000_0000_0000_0000_0001u128, "九無量大數零一");
+    }
+}
+
+#[test]
+fn to_number_compound() {
+    macro_rules! test {
+        ($expect:expr, $largest_unit:expr, $s:expr) => {
+            assert_eq!(Ok($expect), from_chinese_to_u128_compound($s, $largest_unit));
+        };
+    }
+
+    test!(1_0000_0000, ChineseLargestUnit::萬, "一萬萬");
+    test!(1_0000_0000_0000, ChineseLargestUnit::萬, "一萬萬萬");
+    test!(1_0000_0000_0000, ChineseLargestUnit::億, "一萬億");
+    test!(1_0000_0000_0001, ChineseLargestUnit::億, "一萬億零一");
+    test!(1_0000_0000_0000_0000, ChineseLargestUnit::億, "一億億");
+    test!(2_0000_0005_0000_0000, ChineseLargestUnit::億, "二億零五億");
+    test!(3_5000_0000_0000_0000, ChineseLargestUnit::億, "三億五千萬億");
+    test!(1_0000_0000_0000_0000, ChineseLargestUnit::兆, "一萬兆");
+
+    assert_eq!(
+        Ok(-1_0000_0000_0000),
+        from_chinese_to_i128_compound("負一萬億", ChineseLargestUnit::億)
+    );
+    assert_eq_float!(
+        1.5e12,
+        from_chinese_to_f64_compound("一萬五千億", ChineseLargestUnit::億).unwrap()
+    );
+    assert_eq_float!(
+        1e56,
+        from_chinese_to_f64_compound("一萬恆河沙", ChineseLargestUnit::恆河沙).unwrap()
+    );
+    assert_eq_float!(
+        1e56,
+        from_chinese_to_f64_compound("一万恒河沙", ChineseLargestUnit::恆河沙).unwrap()
+    );
+
+    assert_eq!(
+        Err(ChineseToNumberError::DuplicateUnit {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('萬'),
+            },
+        }),
+        from_chinese_to_u128_compound("一萬二萬", ChineseLargestUnit::億)
+    );
+}
+
+#[test]
+fn to_number_prefix() {
+    macro_rules! test {
+        ($t:ty, $expect:expr, $consumed:expr, $s:expr) => {
+            assert_eq!(
+                Ok(($expect as $t, $consumed)),
+                ChineseToNumber::<$t>::to_number_prefix(&$s, ChineseCountMethod::TenThousand)
+            );
+        };
+    }
+
+    test!(u32, 320, 12, "三百二十個蘋果");
+    test!(u32, 320, 12, "三百二十");
+    test!(u32, 34000, 9, "三萬四，");
+    test!(u32, 30000, 4, "3萬人");
+    test!(u32, 10, 3, "十分");
+    test!(i32, -5, 6, "負五度");
+    test!(u64, 1_0000_0000_0000, 9, "一萬億元");
+
+    assert_eq!(
+        Err(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('蘋'),
+            },
+        }),
+        ChineseToNumber::<u32>::to_number_prefix(&"蘋果", ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberEmpty),
+        ChineseToNumber::<u32>::to_number_prefix(&"", ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::Overflow),
+        ChineseToNumber::<u8>::to_number_prefix(&"一千個", ChineseCountMethod::TenThousand)
+    );
+}
+
 #[test]
 fn to_number_naive() {
     test_group_naive!();
@@ -323,14 +519,23 @@ fn to_number_naive() {
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
     test_err!(
-        ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 0
+        ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('a'),
+            },
         },
         "a"
     );
     test_err!(
         ChineseToNumberError::ChineseNumberIncorrect {
-            char_index: 1
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('佰'),
+            },
+            expected: ChineseNumberExpected::Digit,
         },
         "壹佰貳拾佰"
     );
@@ -338,3 +543,712 @@ fn to_number_naive() {
     test_err!(i8, ChineseToNumberError::Overflow, "壹貳捌");
     test_err!(i8, ChineseToNumberError::Underflow, "負壹貳玖");
 }
+
+#[test]
+fn to_range() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, from_chinese_to_range($value, ChineseCountMethod::TenThousand));
+        };
+    }
+
+    test!(Ok(ChineseRange::new(3_0000u32, 5_0000)), "三至五萬");
+    test!(Ok(ChineseRange::new(10u32, 20)), "十到二十");
+    test!(Ok(ChineseRange::new(10u32, 20)), "十～二十");
+    test!(Ok(ChineseRange::new(10u32, 20)), "十 ~ 二十");
+    test!(Ok(ChineseRange::new(10u32, 20)), "十-二十");
+    test!(Ok(ChineseRange::new(10u32, 20)), "十－二十");
+    test!(Ok(ChineseRange::new(30_0000u32, 50_0000)), "三十至五十萬");
+    test!(Ok(ChineseRange::new(3_5000u32, 5_0000)), "三萬五千至五萬");
+    test!(Ok(ChineseRange::new(90u32, 100)), "九十至一百");
+    test!(Ok(ChineseRange::new(2u32, 100)), "二至一百");
+    test!(Ok(ChineseRange::new(-5_0000i32, -3_0000)), "負五萬至負三萬");
+    test!(Ok(ChineseRange::new(5_0000u32, 3_0000)), "五萬至三萬");
+    test!(Ok(ChineseRange::new(3000_0000u32, 5000_0000)), "三至五千萬");
+    test!(Ok(ChineseRange::new(3000_0000u32, 5000_0000)), "三千至五千萬");
+    test!(Ok(ChineseRange::new(20_0000u32, 30_0000)), "二十至三十萬");
+    test!(Ok(ChineseRange::new(5u32, 5_0000)), "五至五萬");
+    test!(Ok(ChineseRange::new(5u32, 3)), "五至三");
+    test!(Ok(ChineseRange::new(5u32, 3_0000)), "五至三萬");
+    test!(Ok(ChineseRange::new(0.5f64, 1.0)), "五角至一");
+
+    test!(Err::<ChineseRange<u32>, _>(ChineseToNumberError::ChineseNumberEmpty), "");
+    test!(
+        Err::<ChineseRange<u32>, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 2,
+                byte_index: 6,
+                char: None,
+            },
+            expected: ChineseNumberExpected::RangeSeparator,
+        }),
+        "三萬"
+    );
+    test!(
+        Err::<ChineseRange<u32>, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('至'),
+            },
+            expected: ChineseNumberExpected::Digit,
+        }),
+        "至五萬"
+    );
+    test!(
+        Err::<ChineseRange<u32>, _>(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 3,
+                byte_index: 9,
+                char: Some('a'),
+            },
+        }),
+        "三至五a"
+    );
+    test!(
+        Err::<ChineseRange<u32>, _>(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 5,
+                byte_index: 11,
+                char: Some('a'),
+            },
+        }),
+        "三 至 五a"
+    );
+    test!(Err::<ChineseRange<u8>, _>(ChineseToNumberError::Overflow), "一至三百");
+}
+
+#[test]
+fn to_approximate_number() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                from_chinese_to_approximate_number($value, ChineseCountMethod::TenThousand)
+            );
+        };
+    }
+
+    test!(Ok(ChineseApproximateNumber::exact(0)), "零");
+    test!(Ok(ChineseApproximateNumber::exact(34)), "三十四");
+    test!(Ok(ChineseApproximateNumber::approximate(11, 19)), "十幾");
+    test!(Ok(ChineseApproximateNumber::approximate(21, 29)), "二十几");
+    test!(Ok(ChineseApproximateNumber::approximate(2, 9)), "幾");
+    test!(Ok(ChineseApproximateNumber::approximate(2000, 9000)), "幾千");
+    test!(Ok(ChineseApproximateNumber::approximate(20_0000, 90_0000)), "幾十萬");
+    test!(Ok(ChineseApproximateNumber::approximate(11_0000, 19_0000)), "十幾萬");
+    test!(Ok(ChineseApproximateNumber::approximate(3, 4)), "三四");
+    test!(Ok(ChineseApproximateNumber::approximate(17, 18)), "十七八");
+    test!(Ok(ChineseApproximateNumber::approximate(30, 40)), "三四十");
+    test!(Ok(ChineseApproximateNumber::approximate(3_0000, 4_0000)), "三四萬");
+    test!(Ok(ChineseApproximateNumber::approximate(150, 160)), "一百五六十");
+    test!(Ok(ChineseApproximateNumber::approximate(105, 106)), "一百零五六");
+    test!(Ok(ChineseApproximateNumber::approximate(100, 999)), "上百");
+    test!(Ok(ChineseApproximateNumber::approximate(1_0000, 9_9999)), "上萬");
+    test!(Ok(ChineseApproximateNumber::approximate(1000_0000, 9999_9999)), "上千萬");
+    test!(Ok(ChineseApproximateNumber::approximate(11, 19)), "十多");
+    test!(Ok(ChineseApproximateNumber::approximate(31, 39)), "三十多");
+    test!(Ok(ChineseApproximateNumber::approximate(101, 199)), "一百多");
+    test!(Ok(ChineseApproximateNumber::approximate(2_0001, 2_9999)), "兩萬多");
+    test!(Ok(ChineseApproximateNumber::approximate(30_0001, 39_9999)), "三十多萬");
+    test!(Ok(ChineseApproximateNumber::approximate(30_0001, 39_9999)), "三十餘萬");
+    test!(Ok(ChineseApproximateNumber::approximate(100_0000_0001, 199_9999_9999)), "一百多億");
+    test!(Ok(ChineseApproximateNumber::approximate(101, 199)), "一百余");
+    test!(Ok(ChineseApproximateNumber::approximate(1000_0001, 1999_9999)), "一千多萬");
+
+    test!(Err(ChineseToNumberError::ChineseNumberEmpty), "");
+    test!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('三'),
+            },
+            expected: ChineseNumberExpected::Unit,
+        }),
+        "三五"
+    );
+    test!(
+        Err(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 3,
+                byte_index: 9,
+                char: Some('a'),
+            },
+        }),
+        "三四十a"
+    );
+    test!(
+        Err(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 6,
+                byte_index: 12,
+                char: Some('a'),
+            },
+        }),
+        "三 四 十 a"
+    );
+    test!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 1,
+                byte_index: 3,
+                char: Some('多'),
+            },
+            expected: ChineseNumberExpected::Unit,
+        }),
+        "三多"
+    );
+    test!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 3,
+                byte_index: 9,
+                char: Some('五'),
+            },
+            expected: ChineseNumberExpected::Unit,
+        }),
+        "三十多五"
+    );
+    test!(
+        Err(ChineseToNumberError::UnknownCharacter {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('上'),
+            },
+        }),
+        "上"
+    );
+}
+
+#[test]
+fn to_interpretations() {
+    use ChineseCountMethod::*;
+
+    macro_rules! test {
+        ($expect:expr, $units:expr, $value:expr) => {
+            let result = from_chinese_to_interpretations::<u128, _>($value).unwrap();
+
+            assert_eq!(
+                $expect.to_vec(),
+                result
+                    .interpretations
+                    .into_iter()
+                    .map(|i| (i.value, i.methods))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                $units.to_vec(),
+                result.ambiguous_units.into_iter().map(|p| p.char_index).collect::<Vec<_>>()
+            );
+        };
+    }
+
+    test!(
+        [(34_0000, vec![Low, TenThousand, Middle, High, Mainland])],
+        [] as [usize; 0],
+        "三十四萬"
+    );
+    test!(
+        [
+            (2_0000_0000_0000, vec![Low]),
+            (20_0000_0000_0000_0000_0000, vec![TenThousand, Mainland]),
+            (20_0000_0000_0000_0000_0000_0000_0000, vec![Middle]),
+            (20_0000_0000_0000_0000_0000_0000_0000_0000_0000, vec![High]),
+        ],
+        [4],
+        "二十萬 京"
+    );
+    test!(
+        [
+            (10_0000_0000, vec![Low]),
+            (1_0000_0000_0000, vec![TenThousand, Middle, High, Mainland]),
+        ],
+        [2],
+        "一萬億"
+    );
+    test!(
+        [
+            (150_0000, vec![Low]),
+            (1_0005_0000_0000, vec![TenThousand]),
+            (1_0000_0005_0000_0000, vec![Middle, High]),
+            (100_0005_0000_0000, vec![Mainland]),
+        ],
+        [1, 3],
+        "一兆五億"
+    );
+
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberEmpty),
+        from_chinese_to_interpretations::<u128, _>(" ")
+    );
+}
+
+#[test]
+fn to_number_with_options() {
+    macro_rules! test {
+        ($expect:expr, $options:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                ChineseToNumber::<i64>::to_number_with_options(
+                    &$value,
+                    ChineseCountMethod::TenThousand,
+                    $options
+                )
+            );
+        };
+    }
+
+    macro_rules! not_allowed {
+        ($char_index:expr, $byte_index:expr, $c:expr) => {
+            Err(ChineseToNumberError::NotAllowed {
+                position: ChineseNumberPosition {
+                    char_index: $char_index,
+                    byte_index: $byte_index,
+                    char:       Some($c),
+                },
+            })
+        };
+    }
+
+    let strict = ChineseParseOptions::strict();
+
+    test!(Ok(120), ChineseParseOptions::default(), "一百二");
+    test!(Ok(2300), ChineseParseOptions::default(), "兩千 3百");
+
+    test!(not_allowed!(2, 6, '二'), strict, "一百二");
+    test!(not_allowed!(2, 6, '四'), strict, "三萬四");
+    test!(not_allowed!(0, 0, '兩'), strict, "兩千");
+    test!(not_allowed!(1, 3, '3'), strict, "三3");
+    test!(not_allowed!(2, 6, ' '), strict, "一百 二十");
+    test!(not_allowed!(2, 6, '貳'), strict, "一百貳拾");
+
+    test!(
+        Ok(102),
+        ChineseParseOptions {
+            allow_non_canonical: true,
+            ..strict
+        },
+        "一百〇二"
+    );
+
+    #[cfg(feature = "number-to-chinese")]
+    {
+        test!(Ok(120), strict, "一百二十");
+        test!(Ok(-102), strict, "負壹佰零貳");
+        test!(Ok(10_0000), strict, "十萬");
+        test!(Ok(1_0002_0000), strict, "一億零二萬");
+        test!(Ok(0), strict, "零");
+
+        test!(not_allowed!(0, 0, '一'), strict, "一十二");
+        test!(not_allowed!(2, 6, '〇'), strict, "一百〇二");
+        test!(not_allowed!(0, 0, '零'), strict, "零零一");
+        test!(not_allowed!(0, 0, '零'), strict, "零一");
+        test!(not_allowed!(3, 9, '零'), strict, "一萬零零五");
+        test!(not_allowed!(2, 6, '零'), strict, "一萬零");
+        test!(not_allowed!(2, 6, '零'), strict, "二百零十");
+
+        test!(
+            Ok(120),
+            ChineseParseOptions {
+                allow_abbreviation: true,
+                ..strict
+            },
+            "一百二"
+        );
+        test!(
+            Ok(2000),
+            ChineseParseOptions {
+                allow_liang: true,
+                ..strict
+            },
+            "兩千"
+        );
+        test!(
+            Ok(120),
+            ChineseParseOptions {
+                allow_mixed_case: true,
+                ..strict
+            },
+            "一百貳拾"
+        );
+    }
+
+    #[cfg(not(feature = "number-to-chinese"))]
+    {
+        test!(Err(ChineseToNumberError::CanonicalCheckUnavailable), strict, "一百二十");
+        test!(Err(ChineseToNumberError::CanonicalCheckUnavailable), strict, "一十二");
+    }
+}
+
+#[test]
+fn localized_error() {
+    macro_rules! test {
+        ($traditional:expr, $simple:expr, $error:expr) => {
+            let error: ChineseToNumberError = $error;
+
+            assert_eq!($traditional, error.to_localized_string(ChineseVariant::Traditional));
+            assert_eq!($simple, error.to_localized_string(ChineseVariant::Simple));
+        };
+    }
+
+    macro_rules! parse {
+        ($value:expr) => {
+            ChineseToNumber::<i32>::to_number(&$value, ChineseCountMethod::TenThousand).unwrap_err()
+        };
+    }
+
+    test!("中文數字不能為空", "中文数字不能为空", parse!(""));
+    test!(
+        "中文數字在「二」處不正確，應為單位（位置：1）",
+        "中文数字在“二”处不正确，应为单位（位置：1）",
+        parse!("一二三")
+    );
+    test!(
+        "中文數字不完整，應為數字（位置：2）",
+        "中文数字不完整，应为数字（位置：2）",
+        ChineseToNumberError::ChineseNumberIncorrect {
+            position: ChineseNumberPosition {
+                char_index: 2,
+                byte_index: 6,
+                char: None,
+            },
+            expected: ChineseNumberExpected::Digit,
+        }
+    );
+    test!(
+        "「a」不是中文數字的一部分（位置：1）",
+        "“a”不是中文数字的一部分（位置：1）",
+        parse!("一a")
+    );
+    test!("單位重複（位置：1）", "单位重复（位置：1）", parse!("一萬二萬"));
+    test!(
+        "單位順序錯誤（位置：0）",
+        "单位顺序错误（位置：0）",
+        ChineseToNumberError::UnitOrder {
+            position: ChineseNumberPosition {
+                char_index: 0,
+                byte_index: 0,
+                char: Some('十'),
+            },
+        }
+    );
+    test!("正負號只能在開頭（位置：1）", "正负号只能在开头（位置：1）", parse!("一負"));
+    test!("此處不能有小數單位（位置：1）", "此处不能有小数单位（位置：1）", parse!("一角五"));
+    test!(
+        "「兩」不符合解析選項（位置：0）",
+        "“兩”不符合解析选项（位置：0）",
+        ChineseParseOptions::strict().check("兩千").unwrap_err()
+    );
+    test!(
+        "需要 number-to-chinese 特性才能檢查標準寫法",
+        "需要 number-to-chinese 特性才能检查标准写法",
+        ChineseToNumberError::CanonicalCheckUnavailable
+    );
+    test!("數值太大", "数值太大", parse!("一百億"));
+    test!("數值太小", "数值太小", parse!("負一百億"));
+}
+
+#[test]
+fn to_count_method() {
+    macro_rules! test {
+        ($method:ident, $confidence:expr, $text:expr) => {
+            let guess = detect_count_method($text).unwrap();
+
+            assert_eq!(ChineseCountMethod::$method, guess.method, "{}", $text);
+            assert_eq_float!($confidence, guess.confidence);
+        };
+    }
+
+    test!(TenThousand, 0.4, "三兆五千億");
+    test!(TenThousand, 0.25, "十二萬");
+    test!(Mainland, 1.0 / 3.0, "一萬億");
+    test!(Middle, 0.5, "一萬兆");
+    test!(High, 1.0, "一億兆");
+    test!(Mainland, 0.5, "一億億");
+    test!(High, 1.0, "一兆兆");
+    test!(Low, 1.0, "一極二載三正");
+    test!(Low, 1.0, "一億二萬");
+    test!(Low, 1.0, "一不可思議二恆河沙");
+
+    assert_eq!(None, detect_count_method(""));
+    assert_eq!(None, detect_count_method("三百二十一"));
+    assert_eq!(None, detect_count_method("一萬"));
+    assert_eq!(None, detect_count_method("正正當當"));
+
+    let mut detector = ChineseCountMethodDetector::new();
+
+    detector.feed("共有三萬億元");
+    detector.feed("又有兩千萬億元");
+
+    let guess = detector.guess().unwrap();
+
+    assert_eq!(ChineseCountMethod::Mainland, guess.method);
+    assert_eq_float!(0.3, guess.confidence);
+
+    detector.feed("一億兆");
+
+    assert_eq!(ChineseCountMethod::High, detector.guess().unwrap().method);
+}
+
+#[test]
+fn scan_numbers() {
+    macro_rules! test {
+        ($t:ty, $method:expr, $expect:expr, $text:expr) => {
+            let text = $text;
+
+            let matches: Vec<(&str, $t)> = scan_chinese_numbers(text, $method)
+                .map(|m: ChineseNumberMatch<$t>| (&text[m.range], m.value))
+                .collect();
+
+            assert_eq!($expect as [(&str, $t); _], matches.as_slice());
+        };
+    }
+
+    test!(
+        u32,
+        ChineseCountMethod::TenThousand,
+        [("三千二百", 3200), ("兩", 2)],
+        "共有三千二百人參加，其中兩成來自外地。"
+    );
+    test!(u32, ChineseCountMethod::TenThousand, [("十", 10)], "十分感謝");
+    test!(
+        u32,
+        ChineseCountMethod::TenThousand,
+        [("二〇二六", 2026), ("十", 10)],
+        "二〇二六年十月"
+    );
+    test!(f64, ChineseCountMethod::TenThousand, [("三", 3.0)], "三分鐘");
+    test!(f64, ChineseCountMethod::TenThousand, [("五", 5.0)], "五角形");
+    test!(u64, ChineseCountMethod::TenThousand, [("三", 3)], "一無量大數和三");
+    test!(
+        u32,
+        ChineseCountMethod::TenThousand,
+        [("三", 3), ("一百二十", 120)],
+        "三個人走了一百二十里"
+    );
+    test!(u32, ChineseCountMethod::TenThousand, [("一千萬", 1000_0000)], "一千萬，2024 年");
+    test!(i32, ChineseCountMethod::TenThousand, [("負三", -3), ("五", 5)], "負三度至五度");
+    test!(u32, ChineseCountMethod::TenThousand, [("三", 3), ("五", 5)], "負三度至五度");
+    test!(u64, ChineseCountMethod::Low, [("一恆河沙", 1_0000_0000_0000_0000)], "約一恆河沙");
+    test!(u32, ChineseCountMethod::TenThousand, [("貳拾", 20)], "參考：貳拾元");
+    test!(u32, ChineseCountMethod::TenThousand, [], "");
+
+    let text = "三萬億和一兆";
+
+    let ranges: Vec<_> = ChineseNumberScanner::<u64>::new(text, ChineseCountMethod::Middle)
+        .map(|m| m.range)
+        .collect();
+
+    assert_eq!(vec![0..9, 12..18], ranges);
+
+    let text = "三分鐘";
+
+    let values: Vec<f64> =
+        ChineseNumberScanner::with_money_units(text, ChineseCountMethod::TenThousand)
+            .map(|m| m.value)
+            .collect();
+
+    assert_eq!(vec![0.03], values);
+}
+
+#[test]
+fn to_arabic_text() {
+    macro_rules! test {
+        ($expect:expr, $text:expr) => {
+            assert_eq!(
+                $expect,
+                from_chinese_text_to_arabic($text, ChineseToArabicOptions::default())
+            );
+        };
+    }
+
+    test!("2026年10月18日下午3點，花了1200元", "二〇二六年十月十八日下午三點，花了一千二百元");
+    test!("撥打0912345678", "撥打〇九一二三四五六七八");
+    test!("1997年", "一九九七年");
+    test!("2005年", "兩千零五年");
+    test!("-20度", "負二十度");
+    test!("零下5度", "零下五度");
+    test!("参加的人有35000個", "参加的人有三萬五千個");
+    test!("价格为120元", "价格为壹佰贰拾元");
+    test!("他一心一意，十分努力", "他一心一意，十分努力");
+    test!("10一心一意", "十一心一意");
+    test!("兩三個人", "兩三個人");
+    test!("七八十歲", "七八十歲");
+    test!("十幾個", "十幾個");
+    test!("三十多萬", "三十多萬");
+    test!("十來個", "十來個");
+    test!("一百餘人", "一百餘人");
+    test!("五三", "五三");
+    test!("20", "二〇");
+    test!("3.5公斤", "三點五公斤");
+    test!("12.05公尺", "十二點零五公尺");
+    test!("-0.25", "負零點二五");
+    test!("3點25分", "三點二十五分");
+    test!("3點05分", "三點零五分");
+    test!("一無量大數", "一無量大數");
+    test!("負一無量大數", "負一無量大數");
+    test!("沒有數字", "沒有數字");
+    test!("", "");
+
+    let options = ChineseToArabicOptions {
+        method:     ChineseCountMethod::Middle,
+        exclusions: &["第一"],
+    };
+
+    assert_eq!(
+        "第一名有1000000000000000000000000",
+        from_chinese_text_to_arabic("第一名有一億兆", options)
+    );
+}
+
+#[test]
+fn lex_tokens() {
+    use ChineseNumberTokenKind::{Digit, Point, Sign, Unit, Zero};
+
+    macro_rules! test {
+        ($expect:expr, $s:expr) => {
+            let kinds: Vec<ChineseNumberTokenKind> =
+                ChineseNumberLexer::new($s).map(|token| token.unwrap().kind).collect();
+
+            assert_eq!($expect as [ChineseNumberTokenKind; _], kinds.as_slice());
+        };
+    }
+
+    test!([Digit(2), Digit(2), Digit(2), Digit(2), Digit(2)], "貳贰貮兩2");
+    test!([Zero, Zero, Zero], "零〇0");
+    test!([Unit(ChineseExponent::個), Unit(ChineseExponent::個)], "個个");
+    test!([Unit(ChineseExponent::十), Unit(ChineseExponent::十)], "十拾");
+    test!([Sign(ChineseSign::負), Digit(3), Point, Digit(1), Digit(4)], "负三 點一四");
+    test!([Sign(ChineseSign::正), Digit(5), Unit(ChineseExponent::萬)], " 正五萬");
+    test!([Digit(1), Unit(ChineseExponent::正)], "一正");
+    test!([Digit(1), Unit(ChineseExponent::萬), Unit(ChineseExponent::正)], "一萬正");
+    test!(
+        [Digit(1), Unit(ChineseExponent::不可思議), Unit(ChineseExponent::無量大數)],
+        "一不可思議无量大数"
+    );
+    test!([], "");
+
+    assert_eq!(2, ChineseExponent::個.ordinal());
+    assert_eq!(22, ChineseExponent::無量大數.ordinal());
+
+    let tokens: Vec<_> = ChineseNumberLexer::new("一a萬").collect();
+
+    assert_eq!(
+        vec![
+            Ok(ChineseNumberToken {
+                kind:  Digit(1),
+                range: 0..3,
+            }),
+            Err(ChineseToNumberError::UnknownCharacter {
+                position: ChineseNumberPosition {
+                    char_index: 1,
+                    byte_index: 3,
+                    char: Some('a'),
+                },
+            }),
+            Ok(ChineseNumberToken {
+                kind:  Unit(ChineseExponent::萬),
+                range: 4..7,
+            }),
+        ],
+        tokens
+    );
+}
+
+#[test]
+fn to_ast() {
+    use ChineseNumberAst::{Abbreviated, Digit, Sum, Unit, Zero};
+
+    let ast = |s: &str| from_chinese_to_ast(s, ChineseCountMethod::TenThousand).unwrap();
+
+    assert_eq!(
+        Sum(vec![
+            Unit {
+                coefficient: None,
+                unit:        ChineseExponent::十,
+            },
+            Digit(2),
+        ]),
+        ast("十二")
+    );
+    assert_eq!(
+        Sum(vec![
+            Unit {
+                coefficient: Some(Box::new(Digit(1))),
+                unit:        ChineseExponent::百,
+            },
+            Abbreviated {
+                digit: 2, unit: ChineseExponent::百
+            },
+        ]),
+        ast("一百二")
+    );
+    assert_eq!(
+        Sum(vec![
+            Unit {
+                coefficient: Some(Box::new(Digit(1))),
+                unit:        ChineseExponent::千,
+            },
+            Zero,
+            Digit(5),
+        ]),
+        ast("一千零五")
+    );
+    assert_eq!(Zero, ast("零"));
+
+    assert_eq!("(3×千 + 2×百)×萬", ast("三千二百萬").to_string());
+    assert_eq!("1×萬 + 1×萬÷10", ast("一萬一").to_string());
+    assert_eq!("(十×萬 + 2×千)×億 + 0 + 5", ast("十萬二千億零五").to_string());
+
+    for method in ChineseCountMethod::variants() {
+        for s in [
+            "零", "七", "十", "十二", "二十", "一百二", "一百零二", "三千二百萬", "一萬一",
+            "十萬零一", "一億二千萬", "九千九百九十九萬九千九百九十九", "一兆零一", "五十萬億",
+        ] {
+            let expect: Result<u128, _> = s.to_number(method);
+
+            match from_chinese_to_ast(s, method) {
+                Ok(ast) => assert_eq!(expect, ast.to_u128(method), "{s} {method:?}"),
+                Err(error) => assert_eq!(Err(error), expect, "{s} {method:?}"),
+            }
+        }
+    }
+
+    assert_eq!(
+        Err(ChineseToNumberError::Overflow),
+        ast("一無量大數").to_u128(ChineseCountMethod::TenThousand)
+    );
+}
+
+#[test]
+fn detect_style() {
+    use ChineseCase::{Lower, Upper};
+    use ChineseDetected::{Mixed, Uniform, Unknown};
+    use ChineseVariant::{Simple, Traditional};
+
+    macro_rules! test {
+        ($variant:expr, $case:expr, $zero:expr, $liang:expr, $abbreviated:expr, $s:expr) => {
+            let style = detect_number_style($s, ChineseCountMethod::TenThousand);
+
+            assert_eq!($variant, style.chinese_variant, "{}", $s);
+            assert_eq!($case, style.chinese_case, "{}", $s);
+            assert_eq!($zero, style.zero, "{}", $s);
+            assert_eq!($liang, style.liang, "{}", $s);
+            assert_eq!($abbreviated, style.abbreviated, "{}", $s);
+        };
+    }
+
+    test!(Uniform(Simple), Uniform(Upper), Unknown, false, false, "壹佰贰拾");
+    test!(Uniform(Traditional), Uniform(Upper), Uniform('零'), false, false, "壹佰零貳");
+    test!(Unknown, Uniform(Lower), Unknown, false, false, "一百二十");
+    test!(Unknown, Mixed, Unknown, false, false, "壹百二十");
+    test!(Mixed, Uniform(Lower), Unknown, false, false, "一萬一千亿");
+    test!(Unknown, Unknown, Unknown, false, false, "5兆6京");
+    test!(Uniform(Traditional), Uniform(Lower), Unknown, true, true, "兩萬二");
+    test!(Uniform(Simple), Uniform(Lower), Mixed, true, false, "两百〇零五");
+    test!(Uniform(Simple), Uniform(Lower), Unknown, false, true, "负一百二点五");
+    test!(Unknown, Unknown, Uniform('0'), false, false, "2020");
+    test!(Unknown, Unknown, Unknown, false, false, "");
+}
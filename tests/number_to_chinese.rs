@@ -1,7 +1,11 @@
 #![cfg(feature = "number-to-chinese")]
 
 use chinese_number::{
-    ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese, NumberToChineseError,
+    ArabicToChineseOptions, ChineseApproximateNumber, ChineseCase, ChineseCountMethod,
+    ChineseLargestUnit, ChineseVariant, NumberToChinese, NumberToChineseError,
+    from_approximate_number_to_chinese_quantity, from_arabic_text_to_chinese,
+    from_f32_to_chinese_decimal_unit, from_f64_to_chinese_compound, from_i128_to_chinese_compound,
+    from_u128_to_chinese_compound, from_u128_to_chinese_quantity,
 };
 
 macro_rules! test_group {
@@ -74,12 +78,15 @@ fn to_uppercase_low() {
         -9999999999999999i128
     );
     test!("玖極玖載玖正玖澗玖溝玖穰玖秭玖垓玖京玖兆玖億玖萬玖仟玖佰玖拾玖", 9999999999999999u128);
+    test!("壹恆河沙", 1_0000_0000_0000_0000u128);
+    test!("玖無量大數零壹", 9_0000_0000_0000_0000_0001u128);
 
     test!("壹佰貳拾參肆角陸分", 123.456f32);
     test!("負壹佰貳拾參肆角陸分", -123.456f32);
     test!("壹佰貳拾參肆角陸分", 123.456f64);
     test!("負壹佰貳拾參肆角陸分", -123.456f64);
 
+    test_err!(NumberToChineseError::Overflow, 10_0000_0000_0000_0000_0000u128);
     test_err!(NumberToChineseError::Overflow, i128::MAX);
     test_err!(NumberToChineseError::Underflow, i128::MIN);
     test_err!(NumberToChineseError::Overflow, u128::MAX);
@@ -319,6 +326,27 @@ fn to_lowercase_high() {
     test!("負一百二十三四角六分", -123.456f64);
 }
 
+#[test]
+fn to_lowercase_mainland() {
+    test_group!(ChineseCase::Lower, ChineseCountMethod::Mainland);
+
+    test!("一百二十七", i8::MAX);
+    test!("四十二億九千四百九十六萬七千二百九十五", u32::MAX);
+    test!("一千八百四十四億六千七百四十四萬零七百三十七億零九百五十五萬一千六百一十五", u64::MAX);
+    test!("負九百二十二億三千三百七十二萬零三百六十八億五千四百七十七萬五千八百零八", i64::MIN);
+
+    test!("一百萬", 100_0000u32);
+    test!("一億零五百萬", 1_0500_0000u32);
+    test!("一萬億", 1_0000_0000_0000u64);
+    test!("十二萬億零三", 12_0000_0000_0003u64);
+    test!("一億億", 1_0000_0000_0000_0000u64);
+    test!("三億五千萬億", 3_5000_0000_0000_0000u64);
+    test!("一萬億億零一", 1_0000_0000_0000_0000_0001u128);
+
+    test!("一百二十三四角六分", 123.456f64);
+    test!("一萬五千億億", 1.5e20f64);
+}
+
 #[test]
 fn to_uppercase_naive() {
     test_group_naive!(ChineseCase::Upper);
@@ -365,6 +393,72 @@ fn to_uppercase_naive() {
     test!("負壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零捌壹肆伍貳柒肆貳參柒參壹柒零肆參伍陸柒玖捌零柒零伍陸柒伍貳伍捌肆肆玖玖陸伍玖捌玖壹柒肆柒陸捌零參壹伍柒貳陸零柒捌零零貳捌伍參捌柒陸零伍捌玖伍伍捌陸參貳柒陸陸捌柒捌壹柒壹伍肆零肆伍捌玖伍參伍壹肆參捌貳肆陸肆貳參肆參貳壹參貳陸捌捌玖肆陸肆壹捌貳柒陸捌肆陸柒伍肆陸柒零參伍參柒伍壹陸玖捌陸零肆玖玖壹零伍柒陸伍伍壹貳捌貳零柒陸貳肆伍肆玖零零玖零參捌玖參貳捌玖肆肆零柒伍捌陸捌伍零捌肆伍伍壹參參玖肆貳參零肆伍捌參貳參陸玖零參貳貳貳玖肆捌壹陸伍捌零捌伍伍玖參參貳壹貳參參肆捌貳柒肆柒玖柒捌貳陸貳零肆壹肆肆柒貳參壹陸捌柒參捌壹柒柒壹捌零玖壹玖貳玖玖捌捌壹貳伍零肆零肆零貳陸壹捌肆壹貳肆捌伍捌參陸捌",f64::MIN);
 }
 
+#[test]
+fn to_large_units() {
+    {
+        test_group!(ChineseCase::Lower, ChineseCountMethod::TenThousand);
+
+        test_err!(NumberToChineseError::Overflow, 1e73f64);
+        test_err!(NumberToChineseError::Underflow, -1e73f64);
+    }
+
+    {
+        test_group!(ChineseCase::Lower, ChineseCountMethod::Middle);
+
+        test_err!(NumberToChineseError::Overflow, 1e137f64);
+        test_err!(NumberToChineseError::Underflow, -1e137f64);
+    }
+}
+
+#[test]
+fn to_compound_units() {
+    macro_rules! test {
+        ($expect:expr, $largest_unit:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                from_u128_to_chinese_compound(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    $largest_unit,
+                    $value
+                )
+            );
+        };
+    }
+
+    test!("一萬萬", ChineseLargestUnit::萬, 1_0000_0000);
+    test!("一萬零五萬", ChineseLargestUnit::萬, 1_0005_0000);
+    test!("一萬萬萬零一", ChineseLargestUnit::萬, 1_0000_0000_0001);
+    test!("一億零五萬", ChineseLargestUnit::億, 1_0005_0000);
+    test!("一萬億", ChineseLargestUnit::億, 1_0000_0000_0000);
+    test!("一萬億零一", ChineseLargestUnit::億, 1_0000_0000_0001);
+    test!("一億億", ChineseLargestUnit::億, 1_0000_0000_0000_0000);
+    test!("二億零五億", ChineseLargestUnit::億, 2_0000_0005_0000_0000);
+    test!("三億五千萬億", ChineseLargestUnit::億, 3_5000_0000_0000_0000);
+    test!("一兆", ChineseLargestUnit::兆, 1_0000_0000_0000);
+    test!("三萬五千兆", ChineseLargestUnit::兆, 3_5000_0000_0000_0000);
+
+    assert_eq!(
+        "負一萬億",
+        from_i128_to_chinese_compound(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseLargestUnit::億,
+            -1_0000_0000_0000
+        )
+    );
+    assert_eq!(
+        "一萬五千億",
+        from_f64_to_chinese_compound(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseLargestUnit::億,
+            1.5e12
+        )
+        .unwrap()
+    );
+}
+
 #[test]
 fn float_fraction_carries_to_integer() {
     test_group!(ChineseCase::Lower, ChineseCountMethod::TenThousand);
@@ -387,6 +481,31 @@ fn naive_float_fraction_carries_to_integer() {
     test!("負二", -1.995f64);
 }
 
+#[test]
+fn to_decimal_unit() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                from_f32_to_chinese_decimal_unit(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand,
+                    $value
+                )
+                .unwrap()
+            );
+        };
+    }
+
+    test!("三分五釐", 0.35f32);
+    test!("一分", 0.1f32);
+    test!("二毫", 0.002f32);
+    test!("一纖二沙", 0.000_000_12f32);
+    test!("負十二三分四釐五毫六絲", -12.3456f32);
+    test!("一二分三釐", 1.23f32);
+}
+
 #[test]
 fn non_finite_floats_return_errors() {
     {
@@ -405,3 +524,152 @@ fn non_finite_floats_return_errors() {
         test_err!(NumberToChineseError::Underflow, f64::NEG_INFINITY);
     }
 }
+
+#[test]
+fn to_quantity() {
+    macro_rules! test {
+        ($expect:expr, $chinese_variant:expr, $chinese_case:expr, $value:expr, $measure_word:expr) => {
+            assert_eq!(
+                $expect,
+                from_u128_to_chinese_quantity(
+                    $chinese_variant,
+                    $chinese_case,
+                    ChineseCountMethod::TenThousand,
+                    $value,
+                    $measure_word
+                )
+                .unwrap()
+            );
+        };
+    }
+
+    test!("兩個", ChineseVariant::Traditional, ChineseCase::Lower, 2, "個");
+    test!("两个", ChineseVariant::Simple, ChineseCase::Lower, 2, "個");
+    test!("一個", ChineseVariant::Traditional, ChineseCase::Lower, 1, "个");
+    test!("兩本", ChineseVariant::Traditional, ChineseCase::Lower, 2, "本");
+    test!("十二個", ChineseVariant::Traditional, ChineseCase::Lower, 12, "個");
+    test!("二十個", ChineseVariant::Traditional, ChineseCase::Lower, 20, "個");
+    test!("二十二個", ChineseVariant::Traditional, ChineseCase::Lower, 22, "個");
+    test!("兩百個", ChineseVariant::Traditional, ChineseCase::Lower, 200, "個");
+    test!("兩千零二個", ChineseVariant::Traditional, ChineseCase::Lower, 2002, "個");
+    test!("两万个", ChineseVariant::Simple, ChineseCase::Lower, 2_0000, "個");
+    test!("一百零二個", ChineseVariant::Traditional, ChineseCase::Lower, 102, "個");
+    test!("貳個", ChineseVariant::Traditional, ChineseCase::Upper, 2, "個");
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        from_u128_to_chinese_quantity(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low,
+            u128::MAX,
+            "個"
+        )
+    );
+}
+
+#[test]
+fn to_approximate_quantity() {
+    macro_rules! test {
+        ($expect:expr, $chinese_variant:expr, $min:expr, $max:expr) => {
+            assert_eq!(
+                $expect,
+                from_approximate_number_to_chinese_quantity(
+                    $chinese_variant,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand,
+                    ChineseApproximateNumber::approximate($min, $max),
+                    "個"
+                )
+                .unwrap()
+            );
+        };
+    }
+
+    test!("三四個", ChineseVariant::Traditional, 3, 4);
+    test!("兩三個", ChineseVariant::Traditional, 2, 3);
+    test!("一兩個", ChineseVariant::Traditional, 1, 2);
+    test!("十七八個", ChineseVariant::Traditional, 17, 18);
+    test!("二三十個", ChineseVariant::Traditional, 20, 30);
+    test!("兩三百個", ChineseVariant::Traditional, 200, 300);
+    test!("三四萬個", ChineseVariant::Traditional, 3_0000, 4_0000);
+    test!("十幾個", ChineseVariant::Traditional, 11, 19);
+    test!("十几个", ChineseVariant::Simple, 11, 19);
+    test!("二十幾個", ChineseVariant::Traditional, 21, 29);
+    test!("幾個", ChineseVariant::Traditional, 2, 9);
+    test!("幾千個", ChineseVariant::Traditional, 2000, 9000);
+    test!("十幾萬個", ChineseVariant::Traditional, 11_0000, 19_0000);
+    test!("上百個", ChineseVariant::Traditional, 100, 999);
+    test!("上萬個", ChineseVariant::Traditional, 1_0000, 9_9999);
+    test!("三十幾個", ChineseVariant::Traditional, 31, 39);
+    test!("一百多個", ChineseVariant::Traditional, 101, 199);
+    test!("兩萬多個", ChineseVariant::Traditional, 2_0001, 2_9999);
+    test!("五至十二個", ChineseVariant::Traditional, 5, 12);
+    test!("三至五萬個", ChineseVariant::Traditional, 3_0000, 5_0000);
+    test!("兩千幾百個", ChineseVariant::Traditional, 2100, 2900);
+    test!("二十一至三十一個", ChineseVariant::Traditional, 21, 31);
+
+    assert_eq!(
+        "兩個",
+        from_approximate_number_to_chinese_quantity(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand,
+            ChineseApproximateNumber::exact(2),
+            "個"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn from_arabic_text() {
+    macro_rules! test {
+        ($expect:expr, $text:expr) => {
+            assert_eq!(
+                $expect,
+                from_arabic_text_to_chinese($text, ArabicToChineseOptions::default())
+            );
+        };
+    }
+
+    test!(
+        "二零二六年十月十八日 價格$一千二百三十四點五 電話零九一二三四五六七八",
+        "2026年10月18日 價格$1,234.5 電話0912-345-678"
+    );
+    test!("一百二十三萬四千五百六十七人", "1,234,567人");
+    test!("一,二千三百四十五", "1,2345");
+    test!("溫度負五點二五度", "溫度-5.25度");
+    test!("A-一型", "A-1型");
+    test!("第十-十二頁", "第10-12頁");
+    test!("一二三四五六七", "123-4567");
+    test!("零點五", "0.5");
+    test!("零零七", "007");
+    test!("結束於三.", "結束於3.");
+    test!("沒有數字", "沒有數字");
+    test!("", "");
+
+    let options = ArabicToChineseOptions {
+        chinese_variant: ChineseVariant::Simple,
+        chinese_case:    ChineseCase::Upper,
+        method:          ChineseCountMethod::TenThousand,
+    };
+
+    assert_eq!("负叁万元", from_arabic_text_to_chinese("-30000元", options));
+    assert_eq!("贰零贰陆年", from_arabic_text_to_chinese("2026年", options));
+}
+
+#[test]
+fn localized_error() {
+    assert_eq!(
+        "數值太大",
+        f64::NAN
+            .to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::High)
+            .unwrap_err()
+            .to_localized_string(ChineseVariant::Traditional)
+    );
+    assert_eq!(
+        "数值太小",
+        NumberToChineseError::Underflow.localized(ChineseVariant::Simple).to_string()
+    );
+}
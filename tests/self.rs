@@ -6,7 +6,12 @@ use core::{
 };
 
 use chinese_number::{
-    ChineseCase, ChineseCountMethod, ChineseToNumber, ChineseVariant, NumberToChinese,
+    ChineseApproximateNumber, ChineseCase, ChineseCountMethod, ChineseParseOptions, ChineseRange,
+    ChineseRangeSeparator, ChineseToNumber, ChineseToNumberError, ChineseVariant, NumberToChinese,
+    NumberToChineseError, convert_method, from_approximate_number_to_chinese_quantity,
+    from_chinese_to_approximate_number, from_chinese_to_f64_decimal_unit, from_chinese_to_range,
+    from_f32_to_chinese_decimal_unit, from_f64_to_chinese_decimal_unit, from_range_to_chinese,
+    is_canonical, normalize, transliterate,
 };
 use num_traits::{CheckedAdd, CheckedMul};
 
@@ -268,7 +273,7 @@ fn test_i64() {
 
 #[test]
 fn test_u128() {
-    ranger(u128::MIN..=9999_9999_9999_9999, |i| {
+    ranger(u128::MIN..=9_9999_9999_9999_9999_9999, |i| {
         assert_eq!(
             i,
             i.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::Low)
@@ -303,7 +308,7 @@ fn test_u128() {
 
 #[test]
 fn test_i128() {
-    ranger(-9999_9999_9999_9999i128..=9999_9999_9999_9999, |i| {
+    ranger(-9_9999_9999_9999_9999_9999i128..=9_9999_9999_9999_9999_9999, |i| {
         assert_eq!(
             i,
             i.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::Low)
@@ -417,3 +422,339 @@ fn test_isize() {
         );
     });
 }
+
+#[test]
+fn test_f64_decimal_unit() {
+    for method in ChineseCountMethod::variants() {
+        for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+            for i in [0.0, 0.35, 1.05, -12.3456, 100.000_001, 0.000_000_12, 12345.6789] {
+                assert_eq!(
+                    i,
+                    from_chinese_to_f64_decimal_unit(
+                        from_f64_to_chinese_decimal_unit(
+                            chinese_variant,
+                            ChineseCase::Lower,
+                            method,
+                            i
+                        )
+                        .unwrap(),
+                        method
+                    )
+                    .unwrap()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_f32_decimal_unit() {
+    for method in ChineseCountMethod::variants() {
+        for i in [0.0f32, 0.35, 1.05, -12.3456, 0.000_000_12, 1234.5678] {
+            assert_eq!(
+                i,
+                from_chinese_to_f64_decimal_unit(
+                    from_f32_to_chinese_decimal_unit(
+                        ChineseVariant::Traditional,
+                        ChineseCase::Lower,
+                        method,
+                        i
+                    )
+                    .unwrap(),
+                    method
+                )
+                .unwrap() as f32
+            );
+        }
+    }
+}
+
+#[test]
+fn test_range() {
+    for method in ChineseCountMethod::variants() {
+        ranger(1u64..=u32::MAX as u64, |high| {
+            for low in [0, high / 10, high / 3, high / 2, high - 1, high] {
+                let range = ChineseRange::new(low, high);
+
+                assert_eq!(
+                    Ok(range),
+                    from_chinese_to_range(
+                        from_range_to_chinese(
+                            ChineseVariant::Traditional,
+                            ChineseCase::Lower,
+                            method,
+                            ChineseRangeSeparator::To,
+                            range
+                        )
+                        .unwrap(),
+                        method
+                    )
+                );
+            }
+        });
+    }
+}
+
+#[test]
+fn test_range_written_forms() {
+    macro_rules! test {
+        ($expect:expr, $separator:expr, $low:expr, $high:expr) => {
+            assert_eq!(
+                $expect,
+                from_range_to_chinese(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand,
+                    $separator,
+                    ChineseRange::new($low, $high)
+                )
+                .unwrap()
+            );
+        };
+    }
+
+    test!("三至五萬", ChineseRangeSeparator::To, 3_0000, 5_0000);
+    test!("十到二十", ChineseRangeSeparator::Until, 10, 20);
+    test!("三十至五十萬", ChineseRangeSeparator::To, 30_0000, 50_0000);
+    test!("三千至五千萬", ChineseRangeSeparator::To, 3000_0000, 5000_0000);
+    test!("一至二百", ChineseRangeSeparator::To, 100, 200);
+    test!("三千至五萬", ChineseRangeSeparator::To, 3000, 5_0000);
+    test!("三萬五千至五萬", ChineseRangeSeparator::To, 3_5000, 5_0000);
+    test!("一億零三萬至一億零五萬", ChineseRangeSeparator::To, 1_0003_0000, 1_0005_0000);
+    test!("五萬至三萬", ChineseRangeSeparator::To, 5_0000, 3_0000);
+    test!("負五萬至負三萬", ChineseRangeSeparator::To, -5_0000, -3_0000);
+    test!("一至二京", ChineseRangeSeparator::To, 1e16, 2e16);
+    test!("五至五萬", ChineseRangeSeparator::To, 5, 5_0000);
+    test!("五至三", ChineseRangeSeparator::To, 5, 3);
+
+    for (low, high) in [(3, 5_0000), (2, 300), (1200, 5000_0000)] {
+        assert_eq!(
+            Err(NumberToChineseError::AmbiguousRange),
+            from_range_to_chinese(
+                ChineseVariant::Traditional,
+                ChineseCase::Lower,
+                ChineseCountMethod::TenThousand,
+                ChineseRangeSeparator::To,
+                ChineseRange::new(low, high)
+            )
+        );
+    }
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        from_range_to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low,
+            ChineseRangeSeparator::To,
+            ChineseRange::new(0u128, 10_0000_0000_0000_0000_0000)
+        )
+    );
+}
+
+#[test]
+fn test_approximate_number() {
+    for method in ChineseCountMethod::variants() {
+        for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+            for (min, max) in [
+                (1, 2),
+                (2, 3),
+                (17, 18),
+                (20, 30),
+                (200, 300),
+                (3_0000, 4_0000),
+                (11, 19),
+                (21, 29),
+                (2, 9),
+                (2000, 9000),
+                (11_0000, 19_0000),
+                (100, 999),
+                (1_0000, 9_9999),
+                (31, 39),
+                (101, 199),
+                (111, 119),
+                (2_0001, 2_9999),
+            ] {
+                let number = ChineseApproximateNumber::approximate(min, max);
+
+                assert_eq!(
+                    Ok(number),
+                    from_chinese_to_approximate_number(
+                        from_approximate_number_to_chinese_quantity(
+                            chinese_variant,
+                            ChineseCase::Lower,
+                            method,
+                            number,
+                            ""
+                        )
+                        .unwrap(),
+                        method
+                    ),
+                    "{min} to {max}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_normalize() {
+    for method in ChineseCountMethod::variants() {
+        for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+            for chinese_case in [ChineseCase::Upper, ChineseCase::Lower] {
+                for i in [0.0, 0.5, 0.05, -12.34, 10.0, 1_0010.0, 1_0000_0000.0, 1e20, 1e40] {
+                    let Ok(s) = i.to_chinese(chinese_variant, chinese_case, method) else {
+                        continue;
+                    };
+
+                    assert!(is_canonical(&s, ChineseParseOptions::strict()), "{s}");
+
+                    let normalized = normalize(&s, chinese_variant, chinese_case, method).unwrap();
+
+                    assert_eq!(s, normalized.chinese);
+                    assert!(!normalized.reinterpreted, "{s}");
+                }
+
+                for i in [0.35, 1.05, -12.3456, 0.000_000_12] {
+                    let s =
+                        from_f64_to_chinese_decimal_unit(chinese_variant, chinese_case, method, i)
+                            .unwrap();
+
+                    assert!(is_canonical(&s, ChineseParseOptions::strict()), "{s}");
+                    assert_eq!(
+                        s,
+                        normalize(&s, chinese_variant, chinese_case, method).unwrap().chinese
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_normalize_written_forms() {
+    macro_rules! test {
+        ($expect:expr, $reinterpreted:expr, $value:expr) => {
+            let normalized = normalize(
+                $value,
+                ChineseVariant::Traditional,
+                ChineseCase::Lower,
+                ChineseCountMethod::TenThousand,
+            )
+            .unwrap();
+
+            assert_eq!($expect, normalized.chinese);
+            assert_eq!($reinterpreted, normalized.reinterpreted, "{}", $value);
+        };
+    }
+
+    test!("一百零二", false, "壹佰零貳");
+    test!("一百零二", false, "一百〇二");
+    test!("一百二十", false, "百二");
+    test!("二千三百", false, "兩千三");
+    test!("三萬", false, "3 萬");
+    test!("負五角", false, "負五角");
+    test!("三分五釐", false, "三分五厘");
+    test!("一無量大數", false, "一無量大數");
+    test!("一兆", true, "一萬億");
+    test!("一萬", false, "十千");
+
+    let lenient = ChineseParseOptions::lenient();
+    let strict = ChineseParseOptions::strict();
+
+    assert!(is_canonical("一百二十", strict));
+    assert!(is_canonical("负一百二十", strict));
+    assert!(!is_canonical("一百 二十", strict));
+    assert!(is_canonical("一百 二十", lenient));
+    assert!(!is_canonical("兩千", strict));
+    assert!(is_canonical("兩千", lenient));
+    assert!(is_canonical("一億五", lenient));
+    assert!(!is_canonical("一百〇二", lenient));
+    assert!(!is_canonical("一十", lenient));
+    assert!(is_canonical("一萬億", strict));
+}
+
+#[test]
+fn test_transliterate() {
+    let styles = [ChineseVariant::Traditional, ChineseVariant::Simple].into_iter().flat_map(
+        |chinese_variant| {
+            [ChineseCase::Upper, ChineseCase::Lower]
+                .into_iter()
+                .map(move |chinese_case| (chinese_variant, chinese_case))
+        },
+    );
+
+    for method in ChineseCountMethod::variants() {
+        for value in [0i128, 2, 10, 12, 102, 3000, -123456789, i128::MAX] {
+            for (from_variant, from_case) in styles.clone() {
+                let Ok(s) = value.to_chinese(from_variant, from_case, method) else {
+                    continue;
+                };
+
+                for (chinese_variant, chinese_case) in styles.clone() {
+                    assert_eq!(
+                        value.to_chinese(chinese_variant, chinese_case, method).unwrap(),
+                        transliterate(&s, chinese_variant, chinese_case)
+                    );
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        "九无量大数九不可思议",
+        transliterate("玖無量大數玖不可思議", ChineseVariant::Simple, ChineseCase::Lower)
+    );
+    assert_eq!(
+        "兩萬二 〇五",
+        transliterate("两万二 〇五", ChineseVariant::Traditional, ChineseCase::Lower)
+    );
+    assert_eq!(
+        "參分伍釐 3億",
+        transliterate("三分五厘 3亿", ChineseVariant::Traditional, ChineseCase::Upper)
+    );
+    assert_eq!("几十个人", transliterate("幾十個人", ChineseVariant::Simple, ChineseCase::Lower));
+}
+
+#[test]
+fn test_convert_method() {
+    for from in ChineseCountMethod::variants() {
+        for to in ChineseCountMethod::variants() {
+            for value in [0u64, 7, 1234, 1_0000_0000, 3_0000_0000_0005, 9999_9999_9999_9999] {
+                let Ok(s) = value.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, from)
+                else {
+                    continue;
+                };
+
+                let converted = convert_method(&s, from, to);
+
+                match value.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, to) {
+                    Ok(expect) => assert_eq!(Ok(expect), converted, "{s} {from:?} {to:?}"),
+                    Err(_) => assert_eq!(Err(ChineseToNumberError::Overflow), converted),
+                }
+            }
+        }
+    }
+
+    macro_rules! test {
+        ($expect:expr, $from:ident, $to:ident, $value:expr) => {
+            assert_eq!(
+                $expect,
+                convert_method($value, ChineseCountMethod::$from, ChineseCountMethod::$to)
+                    .as_deref()
+            );
+        };
+    }
+
+    test!(Ok("一百萬"), Low, TenThousand, "一兆");
+    test!(Ok("一千二百萬"), Low, TenThousand, "一京二兆");
+    test!(Ok("負三萬億"), TenThousand, Middle, "負三兆");
+    test!(Ok("一秭"), Middle, TenThousand, "一京");
+    test!(Ok("二百萬"), Low, TenThousand, "兩兆");
+    test!(Ok("一澗五角"), TenThousand, Low, "一兆五角");
+    test!(Ok("贰亿叁仟万"), Low, TenThousand, "贰垓叁京");
+    test!(Ok("二億一千萬"), Low, TenThousand, "二垓一京");
+    test!(Ok("一無量大數"), High, High, "一無量大數");
+    test!(Err(&ChineseToNumberError::Overflow), High, TenThousand, "一極");
+    test!(Err(&ChineseToNumberError::Underflow), High, Low, "負一極");
+}
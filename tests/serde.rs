@@ -0,0 +1,92 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Payment {
+    #[serde(rename = "金額", with = "chinese_number::serde::ten_thousand_upper")]
+    amount:   u64,
+    #[serde(with = "chinese_number::serde::simple::ten_thousand_lower")]
+    discount: f64,
+    #[serde(with = "chinese_number::serde::low_lower::number_or_chinese")]
+    count:    i32,
+}
+
+#[test]
+fn serialize() {
+    let payment = Payment {
+        amount:   1200,
+        discount: 0.5,
+        count:    -1000000,
+    };
+
+    assert_eq!(
+        r#"{"金額":"壹仟貳佰","discount":"五角","count":"負一兆"}"#,
+        serde_json::to_string(&payment).unwrap()
+    );
+
+    #[derive(Serialize)]
+    struct Huge {
+        #[serde(with = "chinese_number::serde::low_upper")]
+        value: u128,
+    }
+
+    assert!(serde_json::to_string(&Huge {
+        value: u128::MAX
+    })
+    .is_err());
+}
+
+#[test]
+fn deserialize() {
+    let expect = Payment {
+        amount:   1200,
+        discount: 0.5,
+        count:    -1000000,
+    };
+
+    assert_eq!(
+        expect,
+        serde_json::from_str(r#"{"金額":"一千二百","discount":"伍角","count":"負一兆"}"#).unwrap()
+    );
+    assert_eq!(
+        expect,
+        serde_json::from_str(r#"{"金額":"壹仟貳佰","discount":"五角","count":-1000000}"#).unwrap()
+    );
+
+    for json in [
+        r#"{"金額":1200,"discount":"五角","count":-1000000}"#,
+        r#"{"金額":"一千二百","discount":0.5,"count":-1000000}"#,
+        r#"{"金額":"一千二百","discount":"五角","count":1.5}"#,
+        r#"{"金額":"一千二百","discount":"五角","count":3000000000}"#,
+        r#"{"金額":"一千二百","discount":"五角","count":true}"#,
+        r#"{"金額":"一千兩百X","discount":"五角","count":1}"#,
+    ] {
+        assert!(serde_json::from_str::<Payment>(json).is_err(), "{json}");
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Ratio {
+        #[serde(with = "chinese_number::serde::ten_thousand_lower::number_or_chinese")]
+        value: f32,
+    }
+
+    assert_eq!(
+        Ratio {
+            value: 1.5
+        },
+        serde_json::from_str(r#"{"value":1.5}"#).unwrap()
+    );
+    assert_eq!(
+        Ratio {
+            value: 3.0
+        },
+        serde_json::from_str(r#"{"value":"三"}"#).unwrap()
+    );
+
+    let error = serde_json::from_str::<Payment>(r#"{"金額":"一千萬萬","discount":"五角"}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("the unit is repeated (position: 2)"), "{error}");
+}